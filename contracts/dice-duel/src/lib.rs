@@ -2,8 +2,33 @@
 
 //! # Dice Duel
 //!
-//! A two-player dice game where each player rolls two dice.
-//! The player with the highest total wins (ties go to player 1).
+//! A two-player dice game. Each game is played with a `DiceConfig` fixed at
+//! `start_game`: the number of dice per player, the number of sides (d4-d20),
+//! and the scoring rule used to compare rolls. The classic game is two d6
+//! scored by their sum. The player with the highest score wins a round; tied
+//! rounds are re-rolled. A game is a best-of-N match (`DiceConfig::best_of`,
+//! default 1) and only ends once a player has won a majority of rounds.
+//!
+//! **Reroll games:** Under the `Yahtzee` rule each player rolls five d6 and may
+//! reroll any unheld dice up to twice before scoring against a chosen category.
+//! Dice are seeded from entropy both players commit (as a keccak256 hash) and
+//! then reveal, so neither player can choose their own rolls. Each reroll is
+//! seeded from that entropy, the reroll index and a further preimage of the
+//! opponent's committed entropy, which the opponent reveals only after the
+//! dice to hold are chosen.
+//!
+//! **Liar's Dice:** Under the `LiarsDice` rule each player's dice are derived
+//! privately from their own committed entropy, mixed with a salt both players
+//! reveal once both have committed. Players alternate increasing bids on how
+//! many dice across both hands show a face, until one calls "liar". Both
+//! players then reveal their entropy and the contract checks the last bid
+//! against the revealed dice.
+//!
+//! **Betting games:** With `DiceConfig::betting` set, each player's first die
+//! is rolled in the open once both players commit entropy, and the remaining
+//! dice stay hidden until both reveal it. In between, players take turns to
+//! raise (locking extra points through the Game Hub), call or fold. A fold
+//! settles the game immediately.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
@@ -11,7 +36,7 @@
 
 use soroban_sdk::{
     Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
-    contracttype, vec
+    contracttype, vec, Vec
 };
 
 // Import GameHub contract interface
@@ -33,6 +58,12 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn add_stake(env: Env, session_id: u32, player: Address, points: i128);
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn cancel_game(env: Env, session_id: u32);
 }
 
 // ============================================================================
@@ -48,12 +79,126 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    InvalidConfig = 6,
+    WrongGameMode = 7,
+    InvalidEntropy = 8,
+    DiceNotRolled = 9,
+    NoRerollsLeft = 10,
+    AlreadyScored = 11,
+    InvalidHoldMask = 12,
+    BothPlayersNotScored = 13,
+    NotYourTurn = 14,
+    InvalidBid = 15,
+    NoBid = 16,
+    NoChallenge = 17,
+    AlreadyChallenged = 18,
+    BettingClosed = 19,
+    BettingOpen = 20,
+    InvalidRaise = 21,
+    DeadlineNotReached = 22,
+    NoIdlePlayer = 23,
+    InvalidTimeout = 24,
+    RerollPending = 25,
+    NoPendingReroll = 26,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundRecord {
+    pub round: u32,
+    pub player1_dice: Vec<u32>,
+    pub player2_dice: Vec<u32>,
+    pub tie_rerolls: u32, // Times the round was re-rolled after a tie
+    pub winner: Address,
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+/// How a player's dice are scored. Higher scores win.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScoringRule {
+    /// Total of all dice
+    Sum,
+    /// Highest single die
+    HighestDie,
+    /// Any matching set beats no set; bigger sets beat smaller ones, then
+    /// higher faces, then the total
+    PairsBeatSums,
+    /// Two d6 only: a natural (7 or 11) beats everything, craps (2, 3 or 12)
+    /// loses to everything, otherwise the total
+    Craps,
+    /// Five d6 only: players reroll held subsets and score a chosen `Category`
+    Yahtzee,
+    /// Hidden dice and bidding; the winner is decided by the final challenge
+    LiarsDice,
+}
+
+/// Scoring categories for `ScoringRule::Yahtzee` games
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Category {
+    Ones,
+    Twos,
+    Threes,
+    Fours,
+    Fives,
+    Sixes,
+    ThreeOfAKind,
+    FourOfAKind,
+    FullHouse,
+    SmallStraight,
+    LargeStraight,
+    Yahtzee,
+    Chance,
+}
+
+/// A Liar's Dice bid: at least `quantity` dice across both hands show `face`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bid {
+    pub player: Address,
+    pub quantity: u32,
+    pub face: u32,
+}
+
+/// One player's progress through a reroll game (Liar's Dice games only use
+/// the entropy commitment)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RerollState {
+    pub commitment: Option<BytesN<32>>, // keccak256(entropy), or keccak256(salt) in Liar's Dice
+    pub entropy: Option<BytesN<32>>,    // Revealed entropy
+    pub salt: Option<BytesN<32>>,       // Liar's Dice: revealed salt = keccak256(entropy)
+    pub rerolls: u32,
+    pub pending_hold: Option<u32>, // Hold mask of a reroll waiting on the opponent's entropy
+    pub reroll_entropy: Option<BytesN<32>>, // Latest entropy revealed for the opponent's rerolls
+    pub score: Option<u32>, // Locked-in score of the chosen category
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiceConfig {
+    pub dice_count: u32,
+    pub sides: u32,
+    pub rule: ScoringRule,
+    pub best_of: u32, // Odd number of rounds; the first to a majority wins
+    pub betting: bool, // Raise, call or fold after seeing the first die
+}
+
+/// Betting progress in a betting game
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BettingState {
+    pub actions: u32, // Raises and calls so far; player 1 acts first
+    pub raises: u32,
+    pub player1_added: i128, // Points added to the starting stake by betting
+    pub player2_added: i128,
+    pub closed: bool, // A call has matched the last raise (or both checked)
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -61,21 +206,35 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    pub config: DiceConfig,
+    pub round: u32, // Current round (1-based)
+    pub player1_score: u32, // Rounds won
+    pub player2_score: u32,
     pub player1_rolled: bool,
     pub player2_rolled: bool,
-    pub player1_die1: Option<u32>,
-    pub player1_die2: Option<u32>,
-    pub player2_die1: Option<u32>,
-    pub player2_die2: Option<u32>,
+    pub player1_dice: Vec<u32>, // Latest round's dice, empty until the first round is revealed
+    pub player2_dice: Vec<u32>,
+    pub player1_reroll: RerollState, // Only used by reroll and Liar's Dice games
+    pub player2_reroll: RerollState,
+    pub bids: Vec<Bid>, // Liar's Dice bid history, player 1 bids first
+    pub challenger: Option<Address>, // Player who called "liar"
+    pub hand_salt: Option<BytesN<32>>, // Liar's Dice: keccak256 of both revealed salts
+    pub betting: BettingState,
     pub winner: Option<Address>,
+    pub drawn: bool, // Settled as a draw because both players stopped acting
+    pub timeout_ledgers: u32, // Ledgers each player has to act after the last action
+    pub deadline: u32, // Ledger after which an idle player can be timed out
+    pub aborted: bool, // Cancelled by both players; all points refunded
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
+    RoundHistory(u32),
     GameHubAddress,
     Admin,
+    TimeoutLedgers,
 }
 
 // ============================================================================
@@ -88,14 +247,521 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers a player has to act before the opponent can claim a timeout
+/// (~1 day), unless the admin configures a different timeout via
+/// `set_timeout_ledgers`
+const DEFAULT_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Longest timeout `set_timeout_ledgers` accepts: a game's storage does not
+/// outlive `GAME_TTL_LEDGERS`, so a longer deadline could never be claimed
+const MAX_TIMEOUT_LEDGERS: u32 = GAME_TTL_LEDGERS;
+
+// ============================================================================
+// Dice Limits
+// ============================================================================
+
+/// Most dice a player may roll
+const MAX_DICE: u32 = 10;
+/// Longest best-of-N match
+const MAX_BEST_OF: u32 = 9;
+/// Tied rounds are re-rolled up to this many times before a seeded coin flip
+/// decides them
+const MAX_TIE_REROLLS: u32 = 16;
+/// Fewest and most sides a die may have (d4 to d20)
+const MIN_SIDES: u32 = 4;
+const MAX_SIDES: u32 = 20;
+
+/// Rules whose dice are seeded from committed player entropy
+const ENTROPY_RULES: [ScoringRule; 2] = [ScoringRule::Yahtzee, ScoringRule::LiarsDice];
+
+/// Raises allowed per betting game
+const MAX_RAISES: u32 = 3;
+
+/// Reroll rounds allowed per player in reroll games
+const MAX_REROLLS: u32 = 2;
+/// Dice per player in reroll games (hold masks use the low five bits)
+const REROLL_DICE: u32 = 5;
+
+// Fixed category scores
+const FULL_HOUSE_SCORE: u32 = 25;
+const SMALL_STRAIGHT_SCORE: u32 = 30;
+const LARGE_STRAIGHT_SCORE: u32 = 40;
+const YAHTZEE_SCORE: u32 = 50;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Roll a single die (1-6) using deterministic PRNG
-fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
+/// Roll a single die (1 to `sides`) using deterministic PRNG
+fn roll_die(env: &Env, seed: BytesN<32>, sides: u32) -> u32 {
     env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=6) as u32
+    env.prng().gen_range::<u64>(1..=sides as u64) as u32
+}
+
+/// Base seed for a game.
+/// Seed components (all deterministic and identical between sim/submit):
+/// 1. Session ID - unique per game
+/// 2. Player addresses - both players contribute
+///
+/// Note: We do NOT include ledger sequence or timestamp because those differ
+/// between simulation and submission, which would cause different winners.
+fn game_base_seed(env: &Env, session_id: u32, game: &Game) -> BytesN<32> {
+    let mut seed_bytes = Bytes::new(env);
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    seed_bytes.append(&game.player1.to_string().to_bytes());
+    seed_bytes.append(&game.player2.to_string().to_bytes());
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Seed for one attempt at a round. The first attempt at round 1 uses the
+/// base seed itself; later rounds and tie re-rolls use
+/// keccak256(base_seed || round || tie_reroll).
+fn round_seed(env: &Env, base_seed: &BytesN<32>, round: u32, tie_reroll: u32) -> BytesN<32> {
+    if round == 1 && tie_reroll == 0 {
+        return base_seed.clone();
+    }
+    let mut seed_bytes = Bytes::from(base_seed.clone());
+    seed_bytes.append(&Bytes::from_array(env, &round.to_be_bytes()));
+    seed_bytes.append(&Bytes::from_array(env, &tie_reroll.to_be_bytes()));
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Seeded coin flip for a tie that survived every re-roll
+fn coin_flip(env: &Env, seed: &BytesN<32>) -> bool {
+    env.prng().seed(seed.clone().into());
+    env.prng().gen_range::<u64>(0..=1) == 0
+}
+
+/// Roll die `n` (1-based) for one player (1 or 2), seeded with
+/// keccak256(seed || [player, n])
+fn roll_seeded_die(env: &Env, seed: &BytesN<32>, player: u8, n: u32, sides: u32) -> u32 {
+    let mut roll_seed_bytes = Bytes::new(env);
+    roll_seed_bytes.append(&Bytes::from(seed.clone()));
+    roll_seed_bytes.append(&Bytes::from_array(env, &[player, n as u8]));
+    let roll_seed = env.crypto().keccak256(&roll_seed_bytes);
+    roll_die(env, roll_seed.into(), sides)
+}
+
+/// Roll all dice for one player (1 or 2) from a round seed
+fn roll_dice(env: &Env, seed: &BytesN<32>, player: u8, config: &DiceConfig) -> Vec<u32> {
+    let mut dice = Vec::new(env);
+    for n in 1..=config.dice_count {
+        dice.push_back(roll_seeded_die(env, seed, player, n, config.sides));
+    }
+    dice
+}
+
+/// Settle a tie with a roll-off of one d6 each, seeded from the entropy seed
+/// at reroll indexes from `first_index` on. Returns whether player 1 won and
+/// how many roll-offs it took.
+fn roll_off(env: &Env, seed: &BytesN<32>, first_index: u32) -> (bool, u32) {
+    for attempt in 0..MAX_TIE_REROLLS {
+        let die1 = roll_reroll_die(env, seed, 1, first_index + attempt, 1);
+        let die2 = roll_reroll_die(env, seed, 2, first_index + attempt, 1);
+        if die1 != die2 {
+            return (die1 > die2, attempt + 1);
+        }
+    }
+    (coin_flip(env, seed), MAX_TIE_REROLLS)
+}
+
+/// Whether a game's dice are seeded from committed player entropy
+fn uses_entropy(config: &DiceConfig) -> bool {
+    ENTROPY_RULES.contains(&config.rule) || config.betting
+}
+
+/// Check a dice configuration is playable
+fn validate_config(config: &DiceConfig) -> Result<(), Error> {
+    if !(1..=MAX_DICE).contains(&config.dice_count)
+        || !(MIN_SIDES..=MAX_SIDES).contains(&config.sides)
+        || !(1..=MAX_BEST_OF).contains(&config.best_of)
+        || config.best_of.is_multiple_of(2)
+    {
+        return Err(Error::InvalidConfig);
+    }
+    // Entropy games are committed once, so they are always a single round
+    if ENTROPY_RULES.contains(&config.rule) && config.best_of != 1 {
+        return Err(Error::InvalidConfig);
+    }
+    if config.rule == ScoringRule::Craps && (config.dice_count != 2 || config.sides != 6) {
+        return Err(Error::InvalidConfig);
+    }
+    if config.rule == ScoringRule::Yahtzee
+        && (config.dice_count != REROLL_DICE || config.sides != 6)
+    {
+        return Err(Error::InvalidConfig);
+    }
+    // Betting needs a hidden die to bet on, and is a single round
+    if config.betting
+        && (ENTROPY_RULES.contains(&config.rule) || config.dice_count < 2 || config.best_of != 1)
+    {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Seed for a reroll game, mixing the game's base seed with both players'
+/// revealed entropy
+fn reroll_game_seed(
+    env: &Env,
+    base_seed: &BytesN<32>,
+    entropy1: &BytesN<32>,
+    entropy2: &BytesN<32>,
+) -> BytesN<32> {
+    let mut seed_bytes = Bytes::from(base_seed.clone());
+    seed_bytes.append(&Bytes::from(entropy1.clone()));
+    seed_bytes.append(&Bytes::from(entropy2.clone()));
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Entropy seed of a game once both players have revealed
+fn entropy_seed(env: &Env, base_seed: &BytesN<32>, game: &Game) -> Result<BytesN<32>, Error> {
+    match (&game.player1_reroll.entropy, &game.player2_reroll.entropy) {
+        (Some(entropy1), Some(entropy2)) => {
+            Ok(reroll_game_seed(env, base_seed, entropy1, entropy2))
+        }
+        _ => Err(Error::DiceNotRolled),
+    }
+}
+
+/// Seed for one reroll: the entropy seed mixed with the entropy the opponent
+/// reveals for it, so the dice of future rerolls are not fixed by the entropy
+/// revealed so far
+fn reroll_seed(env: &Env, game_seed: &BytesN<32>, reroll_entropy: &BytesN<32>) -> BytesN<32> {
+    let mut seed_bytes = Bytes::from(game_seed.clone());
+    seed_bytes.append(&Bytes::from(reroll_entropy.clone()));
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Roll one die of a reroll game.
+/// Seeded with keccak256(game_seed || [player, reroll_index, n]) where reroll
+/// index 0 is the opening roll. Rerolls pass their `reroll_seed`.
+fn roll_reroll_die(env: &Env, game_seed: &BytesN<32>, player: u8, reroll: u32, n: u32) -> u32 {
+    let mut roll_seed_bytes = Bytes::from(game_seed.clone());
+    roll_seed_bytes.append(&Bytes::from_array(env, &[player, reroll as u8, n as u8]));
+    let roll_seed = env.crypto().keccak256(&roll_seed_bytes);
+    roll_die(env, roll_seed.into(), 6)
+}
+
+/// Salt mixed into both Liar's Dice hands, from both players' revealed salts
+fn hand_salt(env: &Env, salt1: &BytesN<32>, salt2: &BytesN<32>) -> BytesN<32> {
+    let mut salt_bytes = Bytes::from(salt1.clone());
+    salt_bytes.append(&Bytes::from(salt2.clone()));
+    env.crypto().keccak256(&salt_bytes).into()
+}
+
+/// Roll one player's hidden Liar's Dice hand.
+/// Die `n` (1-based) is seeded with
+/// keccak256(base_seed || hand_salt || entropy || [n]).
+/// A player can derive their own dice privately but not their opponent's, and
+/// cannot search for a good hand before committing: the hand salt depends on
+/// the opponent's salt, which is only revealed once both commitments are fixed.
+fn roll_hidden_dice(
+    env: &Env,
+    base_seed: &BytesN<32>,
+    hand_salt: &BytesN<32>,
+    entropy: &BytesN<32>,
+    config: &DiceConfig,
+) -> Vec<u32> {
+    let mut dice = Vec::new(env);
+    for n in 1..=config.dice_count {
+        let mut roll_seed_bytes = Bytes::from(base_seed.clone());
+        roll_seed_bytes.append(&Bytes::from(hand_salt.clone()));
+        roll_seed_bytes.append(&Bytes::from(entropy.clone()));
+        roll_seed_bytes.append(&Bytes::from_array(env, &[n as u8]));
+        let roll_seed = env.crypto().keccak256(&roll_seed_bytes);
+        dice.push_back(roll_die(env, roll_seed.into(), config.sides));
+    }
+    dice
+}
+
+/// Check a bid is in range and raises the previous one: more dice, or the
+/// same number of dice on a higher face
+fn validate_bid(
+    config: &DiceConfig,
+    previous: Option<Bid>,
+    quantity: u32,
+    face: u32,
+) -> Result<(), Error> {
+    let total_dice = config.dice_count * 2;
+    if !(1..=total_dice).contains(&quantity) || !(1..=config.sides).contains(&face) {
+        return Err(Error::InvalidBid);
+    }
+    if let Some(previous) = previous {
+        let raises = quantity > previous.quantity
+            || (quantity == previous.quantity && face > previous.face);
+        if !raises {
+            return Err(Error::InvalidBid);
+        }
+    }
+    Ok(())
+}
+
+/// Score five d6 against a Yahtzee category
+fn score_category(dice: &Vec<u32>, category: Category) -> u32 {
+    let sum: u32 = dice.iter().sum();
+    let mut counts = [0u32; 7];
+    for die in dice.iter() {
+        counts[die as usize] += 1;
+    }
+    let most_of_a_kind = counts.iter().copied().max().unwrap_or(0);
+    // Longest run of consecutive faces present
+    let mut longest_run = 0u32;
+    let mut run = 0u32;
+    for count in counts.iter().skip(1) {
+        run = if *count > 0 { run + 1 } else { 0 };
+        longest_run = longest_run.max(run);
+    }
+    let face_total = |face: u32| face * counts[face as usize];
+
+    match category {
+        Category::Ones => face_total(1),
+        Category::Twos => face_total(2),
+        Category::Threes => face_total(3),
+        Category::Fours => face_total(4),
+        Category::Fives => face_total(5),
+        Category::Sixes => face_total(6),
+        Category::ThreeOfAKind if most_of_a_kind >= 3 => sum,
+        Category::FourOfAKind if most_of_a_kind >= 4 => sum,
+        Category::FullHouse if counts.contains(&3) && counts.contains(&2) => FULL_HOUSE_SCORE,
+        Category::SmallStraight if longest_run >= 4 => SMALL_STRAIGHT_SCORE,
+        Category::LargeStraight if longest_run >= 5 => LARGE_STRAIGHT_SCORE,
+        Category::Yahtzee if most_of_a_kind == 5 => YAHTZEE_SCORE,
+        Category::Chance => sum,
+        _ => 0,
+    }
+}
+
+/// Score a roll under a scoring rule. Higher scores win.
+fn score_dice(dice: &Vec<u32>, rule: ScoringRule) -> u32 {
+    let sum: u32 = dice.iter().sum();
+    match rule {
+        ScoringRule::Sum => sum,
+        ScoringRule::HighestDie => dice.iter().max().unwrap_or(0),
+        ScoringRule::PairsBeatSums => {
+            // Find the biggest matching set, preferring higher faces
+            let mut best_count = 1u32;
+            let mut best_face = 0u32;
+            for face in dice.iter() {
+                let count = dice.iter().filter(|d| *d == face).count() as u32;
+                if count > best_count || (count == best_count && count > 1 && face > best_face) {
+                    best_count = count;
+                    best_face = face;
+                }
+            }
+            if best_count > 1 {
+                // Sum is at most MAX_DICE * MAX_SIDES, so tiers never overlap
+                best_count * 1_000_000 + best_face * 1_000 + sum
+            } else {
+                sum
+            }
+        }
+        ScoringRule::Craps => match sum {
+            7 | 11 => 2_000 + sum,
+            2 | 3 | 12 => sum,
+            _ => 1_000 + sum,
+        },
+        // Reroll games are scored by each player's chosen category and
+        // Liar's Dice by the final challenge
+        ScoringRule::Yahtzee | ScoringRule::LiarsDice => score_category(dice, Category::Chance),
+    }
+}
+
+/// Load an active game whose config is played in `mode`
+fn load_game_for(
+    env: &Env,
+    key: &DataKey,
+    mode: fn(&DiceConfig) -> bool,
+) -> Result<Game, Error> {
+    let game: Game = env
+        .storage()
+        .temporary()
+        .get(key)
+        .ok_or(Error::GameNotFound)?;
+    if game.winner.is_some() || game.drawn || game.aborted {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if !mode(&game.config) {
+        return Err(Error::WrongGameMode);
+    }
+    Ok(game)
+}
+
+/// Load an active Liar's Dice game that is waiting on `player` to bid or
+/// call
+fn load_liars_dice_turn(env: &Env, key: &DataKey, player: &Address) -> Result<Game, Error> {
+    let game = load_game_for(env, key, |config| config.rule == ScoringRule::LiarsDice)?;
+    if *player != game.player1 && *player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    if !game.player1_rolled || !game.player2_rolled {
+        return Err(Error::BothPlayersNotRolled);
+    }
+    if game.hand_salt.is_none() {
+        return Err(Error::DiceNotRolled);
+    }
+    if game.challenger.is_some() {
+        return Err(Error::AlreadyChallenged);
+    }
+    // Player 1 acts on even turns, player 2 on odd turns
+    let player1_turn = game.bids.len().is_multiple_of(2);
+    if (*player == game.player1) != player1_turn {
+        return Err(Error::NotYourTurn);
+    }
+    Ok(game)
+}
+
+/// Load an active betting game that is waiting on `player` to act
+fn load_betting_turn(env: &Env, key: &DataKey, player: &Address) -> Result<Game, Error> {
+    let game = load_game_for(env, key, |config| config.betting)?;
+    if *player != game.player1 && *player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    if !game.player1_rolled || !game.player2_rolled {
+        return Err(Error::BothPlayersNotRolled);
+    }
+    if game.betting.closed {
+        return Err(Error::BettingClosed);
+    }
+    // Player 1 acts on even turns, player 2 on odd turns
+    let player1_turn = game.betting.actions.is_multiple_of(2);
+    if (*player == game.player1) != player1_turn {
+        return Err(Error::NotYourTurn);
+    }
+    Ok(game)
+}
+
+/// Add points to a player's stake in the Game Hub and in the game. A stake
+/// that would overflow is rejected as an invalid raise.
+fn add_stake(
+    env: &Env,
+    session_id: u32,
+    game: &mut Game,
+    player: &Address,
+    points: i128,
+) -> Result<(), Error> {
+    if points == 0 {
+        return Ok(());
+    }
+    let (stake, added) = if *player == game.player1 {
+        (&mut game.player1_points, &mut game.betting.player1_added)
+    } else {
+        (&mut game.player2_points, &mut game.betting.player2_added)
+    };
+    *stake = stake.checked_add(points).ok_or(Error::InvalidRaise)?;
+    *added = added.checked_add(points).ok_or(Error::InvalidRaise)?;
+
+    let game_hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set");
+    let game_hub = GameHubClient::new(env, &game_hub_addr);
+    game_hub.add_stake(&session_id, player, &points);
+    Ok(())
+}
+
+/// Points `player` must add to match the opponent's betting
+fn owed_to_call(game: &Game, player: &Address) -> i128 {
+    if *player == game.player1 {
+        game.betting.player2_added - game.betting.player1_added
+    } else {
+        game.betting.player1_added - game.betting.player2_added
+    }
+}
+
+/// Append the current round to a game's round history
+fn record_round(env: &Env, session_id: u32, game: &Game, tie_rerolls: u32, winner: &Address) {
+    let history_key = DataKey::RoundHistory(session_id);
+    let mut history: Vec<RoundRecord> = env
+        .storage()
+        .temporary()
+        .get(&history_key)
+        .unwrap_or(Vec::new(env));
+    history.push_back(RoundRecord {
+        round: game.round,
+        player1_dice: game.player1_dice.clone(),
+        player2_dice: game.player2_dice.clone(),
+        tie_rerolls,
+        winner: winner.clone(),
+    });
+    env.storage().temporary().set(&history_key, &history);
+    env.storage()
+        .temporary()
+        .extend_ttl(&history_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Give the players a fresh `timeout_ledgers` to act, after any action
+fn restart_clock(env: &Env, game: &mut Game) {
+    game.deadline = env.ledger().sequence() + game.timeout_ledgers;
+}
+
+/// Whether the game is waiting on a player to roll, commit, bet, bid, reveal
+/// or score. Reveals that anyone can call (`reveal_winner`) wait on nobody.
+fn awaiting(game: &Game, player1: bool) -> bool {
+    let (rolled, other_rolled, state) = if player1 {
+        (game.player1_rolled, game.player2_rolled, &game.player1_reroll)
+    } else {
+        (game.player2_rolled, game.player1_rolled, &game.player2_reroll)
+    };
+    if !rolled {
+        return true;
+    }
+    if !other_rolled || !uses_entropy(&game.config) {
+        return false;
+    }
+    if game.config.betting {
+        if !game.betting.closed {
+            return game.betting.actions.is_multiple_of(2) == player1;
+        }
+        return state.entropy.is_none();
+    }
+    if game.config.rule == ScoringRule::LiarsDice {
+        if game.hand_salt.is_none() {
+            return state.salt.is_none();
+        }
+        if game.challenger.is_none() {
+            return game.bids.len().is_multiple_of(2) == player1;
+        }
+        return state.entropy.is_none();
+    }
+    // Reroll games score once both opening hands are rolled, and each reroll
+    // waits on the opponent's entropy
+    let opponent = if player1 { &game.player2_reroll } else { &game.player1_reroll };
+    if opponent.pending_hold.is_some() {
+        return true;
+    }
+    if state.pending_hold.is_some() {
+        return false;
+    }
+    let dice_rolled =
+        game.player1_reroll.entropy.is_some() && game.player2_reroll.entropy.is_some();
+    state.entropy.is_none() || (dice_rolled && state.score.is_none())
+}
+
+/// End a session through the Game Hub.
+/// This unlocks points and updates standings.
+/// Event emitted by the Game Hub contract (GameEnded)
+fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) {
+    let game_hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set");
+    let game_hub = GameHubClient::new(env, &game_hub_addr);
+    game_hub.end_game(&session_id, &player1_won);
+}
+
+/// Cancel a session through the Game Hub with no result.
+/// All locked points, including betting raises, are refunded.
+fn cancel_game_with_hub(env: &Env, session_id: u32) {
+    let game_hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set");
+    let game_hub = GameHubClient::new(env, &game_hub_addr);
+    game_hub.cancel_game(&session_id);
 }
 
 // ============================================================================
@@ -132,6 +798,7 @@ impl DiceDuelContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `config` - Dice count, sides, scoring rule and match length for this game
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -139,15 +806,29 @@ impl DiceDuelContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        config: DiceConfig,
     ) -> Result<(), Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        validate_config(&config)?;
+
+        // Require authentication from both players (they consent to committing points
+        // under these dice rules)
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            config.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            config.into_val(&env),
+        ]);
 
         // Get GameHub address
         let game_hub_addr: Address = env
@@ -170,19 +851,38 @@ impl DiceDuelContract {
             &player2_points,
         );
 
+        // Snapshot the timeout so admin changes don't affect games in progress
+        let timeout_ledgers: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS);
+
         // Create game (dice not rolled yet - will be generated in reveal_winner)
         let game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
+            config,
+            round: 1,
+            player1_score: 0,
+            player2_score: 0,
             player1_rolled: false,
             player2_rolled: false,
-            player1_die1: None,
-            player1_die2: None,
-            player2_die1: None,
-            player2_die2: None,
+            player1_dice: Vec::new(&env),
+            player2_dice: Vec::new(&env),
+            player1_reroll: RerollState::default(),
+            player2_reroll: RerollState::default(),
+            bids: Vec::new(&env),
+            challenger: None,
+            hand_salt: None,
+            betting: BettingState::default(),
             winner: None,
+            drawn: false,
+            aborted: false,
+            timeout_ledgers,
+            deadline: env.ledger().sequence() + timeout_ledgers,
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -194,6 +894,15 @@ impl DiceDuelContract {
             .temporary()
             .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
+        // Start with an empty round history, retained alongside the game
+        let history_key = DataKey::RoundHistory(session_id);
+        env.storage()
+            .temporary()
+            .set(&history_key, &Vec::<RoundRecord>::new(&env));
+        env.storage()
+            .temporary()
+            .extend_ttl(&history_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
         // Event emitted by GameHub contract (GameStarted)
 
         Ok(())
@@ -216,11 +925,16 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner, not drawn and not aborted)
+        if game.winner.is_some() || game.drawn || game.aborted {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // Reroll, Liar's Dice and betting games commit entropy instead
+        if uses_entropy(&game.config) {
+            return Err(Error::WrongGameMode);
+        }
+
         // Update roll commitment for the appropriate player
         if player == game.player1 {
             if game.player1_rolled {
@@ -236,21 +950,523 @@ impl DiceDuelContract {
             return Err(Error::NotPlayer);
         }
 
+        // Every action restarts the clock for the player still to act
+        restart_clock(&env, &mut game);
+
         // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have rolled.
-    /// This generates dice rolls for both players, determines the winner, and ends the session.
+    // ========================================================================
+    // Reroll Games
+    // ========================================================================
+
+    /// Commit entropy for a reroll, Liar's Dice or betting game (replaces `roll`).
+    /// Both players must commit before either reveals. In betting games, each
+    /// player's first die is rolled once both have committed. In Liar's Dice
+    /// the commitment is one hash further out, keccak256(keccak256(entropy)),
+    /// so the inner hash can be revealed as the player's salt (`reveal_salt`)
+    /// without exposing their hand. In reroll games the entropy is itself the
+    /// end of a hash chain whose earlier links answer the opponent's rerolls
+    /// (see `answer_reroll`).
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - keccak256 of the player's 32-byte entropy
+    pub fn commit_entropy(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, uses_entropy)?;
+
+        let (rolled, state) = if player == game.player1 {
+            (&mut game.player1_rolled, &mut game.player1_reroll)
+        } else if player == game.player2 {
+            (&mut game.player2_rolled, &mut game.player2_reroll)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if *rolled {
+            return Err(Error::AlreadyRolled);
+        }
+        *rolled = true;
+        state.commitment = Some(commitment);
+
+        // Betting games open with one visible die each, from the base seed
+        if game.config.betting && game.player1_rolled && game.player2_rolled {
+            let base_seed = game_base_seed(&env, session_id, &game);
+            let sides = game.config.sides;
+            game.player1_dice.push_back(roll_seeded_die(&env, &base_seed, 1, 1, sides));
+            game.player2_dice.push_back(roll_seeded_die(&env, &base_seed, 2, 1, sides));
+        }
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal a Liar's Dice salt, keccak256(entropy), once both players have
+    /// committed. Bidding opens once both salts are revealed; from then on each
+    /// player can derive their own hand, but neither could search for a good
+    /// hand while committing, since the hand salt mixes in both salts.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `salt` - keccak256 of the player's entropy, whose hash was committed
+    pub fn reveal_salt(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, |config| config.rule == ScoringRule::LiarsDice)?;
+
+        if !game.player1_rolled || !game.player2_rolled {
+            return Err(Error::BothPlayersNotRolled);
+        }
+        let state = if player == game.player1 {
+            &mut game.player1_reroll
+        } else if player == game.player2 {
+            &mut game.player2_reroll
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if state.salt.is_some() {
+            return Err(Error::AlreadyRolled);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(salt.clone())).into();
+        if state.commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidEntropy);
+        }
+        state.salt = Some(salt);
+
+        if let (Some(salt1), Some(salt2)) = (&game.player1_reroll.salt, &game.player2_reroll.salt) {
+            game.hand_salt = Some(hand_salt(&env, salt1, salt2));
+        }
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal committed entropy.
+    /// In reroll games, both opening hands are rolled once both players have
+    /// revealed. In Liar's Dice, entropy is revealed after the challenge,
+    /// checked against the player's salt, and rolls the revealing player's
+    /// hidden hand. In betting games, entropy is
+    /// revealed once betting closes and the hidden dice are rolled once both
+    /// players have revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `entropy` - The entropy whose hash was committed
+    pub fn reveal_entropy(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        entropy: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, uses_entropy)?;
+
+        if !game.player1_rolled || !game.player2_rolled {
+            return Err(Error::BothPlayersNotRolled);
+        }
+        let liars_dice = game.config.rule == ScoringRule::LiarsDice;
+        if liars_dice && game.challenger.is_none() {
+            return Err(Error::NoChallenge);
+        }
+        if game.config.betting && !game.betting.closed {
+            return Err(Error::BettingOpen);
+        }
+
+        let base_seed = game_base_seed(&env, session_id, &game);
+        let (state, dice) = if player == game.player1 {
+            (&mut game.player1_reroll, &mut game.player1_dice)
+        } else if player == game.player2 {
+            (&mut game.player2_reroll, &mut game.player2_dice)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if state.entropy.is_some() {
+            return Err(Error::AlreadyRolled);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(entropy.clone())).into();
+        let expected = if liars_dice { &state.salt } else { &state.commitment };
+        if expected.as_ref() != Some(&hash) {
+            return Err(Error::InvalidEntropy);
+        }
+        if let (true, Some(hand_salt)) = (liars_dice, &game.hand_salt) {
+            *dice = roll_hidden_dice(&env, &base_seed, hand_salt, &entropy, &game.config);
+        }
+        state.entropy = Some(entropy);
+
+        // Roll reroll-game opening hands, or betting-game hidden dice, once
+        // both players' entropy is known
+        if let (false, Some(entropy1), Some(entropy2)) = (
+            liars_dice,
+            &game.player1_reroll.entropy,
+            &game.player2_reroll.entropy,
+        ) {
+            let seed = reroll_game_seed(&env, &base_seed, entropy1, entropy2);
+            if game.config.betting {
+                for n in 2..=game.config.dice_count {
+                    let sides = game.config.sides;
+                    game.player1_dice.push_back(roll_seeded_die(&env, &seed, 1, n, sides));
+                    game.player2_dice.push_back(roll_seeded_die(&env, &seed, 2, n, sides));
+                }
+            } else {
+                for n in 1..=REROLL_DICE {
+                    game.player1_dice.push_back(roll_reroll_die(&env, &seed, 1, 0, n));
+                    game.player2_dice.push_back(roll_reroll_die(&env, &seed, 2, 0, n));
+                }
+            }
+        }
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reroll every die not in `hold_mask` (bit `i` holds die `i`).
+    /// Each player may reroll up to `MAX_REROLLS` times before scoring.
+    /// The reroll waits for the opponent to reveal their entropy for it with
+    /// `answer_reroll`, so the new dice are unknown while choosing what to hold.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the rerolling player
+    /// * `hold_mask` - Bitmask of dice to keep (low five bits)
+    pub fn reroll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hold_mask: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        if hold_mask >> REROLL_DICE != 0 {
+            return Err(Error::InvalidHoldMask);
+        }
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, |config| config.rule == ScoringRule::Yahtzee)?;
+        if game.player1_dice.is_empty() {
+            return Err(Error::DiceNotRolled);
+        }
+
+        let state = if player == game.player1 {
+            &mut game.player1_reroll
+        } else if player == game.player2 {
+            &mut game.player2_reroll
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if state.score.is_some() {
+            return Err(Error::AlreadyScored);
+        }
+        if state.pending_hold.is_some() {
+            return Err(Error::RerollPending);
+        }
+        if state.rerolls >= MAX_REROLLS {
+            return Err(Error::NoRerollsLeft);
+        }
+        state.rerolls += 1;
+        state.pending_hold = Some(hold_mask);
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Answer the opponent's pending reroll by revealing the next link of
+    /// the player's entropy chain: bytes whose keccak256 hash is the entropy
+    /// the player revealed last (their opening entropy, then the entropy of
+    /// the previous answer). The opponent's unheld dice are rolled from the
+    /// game's entropy seed, this entropy and the reroll index. A player needs
+    /// `MAX_REROLLS` links beyond their opening entropy, all fixed by their
+    /// commitment before any dice are rolled.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the answering player (the rerolling player's opponent)
+    /// * `entropy` - The next link of the player's entropy chain
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - The rerolling player's dice after the reroll
+    pub fn answer_reroll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        entropy: BytesN<32>,
+    ) -> Result<Vec<u32>, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, |config| config.rule == ScoringRule::Yahtzee)?;
+
+        let base_seed = game_base_seed(&env, session_id, &game);
+        let game_seed = entropy_seed(&env, &base_seed, &game)?;
+        let (index, state, opponent, dice) = if player == game.player1 {
+            (2u8, &mut game.player1_reroll, &mut game.player2_reroll, &mut game.player2_dice)
+        } else if player == game.player2 {
+            (1u8, &mut game.player2_reroll, &mut game.player1_reroll, &mut game.player1_dice)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        let hold_mask = opponent.pending_hold.ok_or(Error::NoPendingReroll)?;
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(entropy.clone())).into();
+        if state.reroll_entropy.as_ref().or(state.entropy.as_ref()) != Some(&hash) {
+            return Err(Error::InvalidEntropy);
+        }
+
+        let seed = reroll_seed(&env, &game_seed, &entropy);
+        for i in 0..REROLL_DICE {
+            if hold_mask & (1 << i) == 0 {
+                dice.set(i, roll_reroll_die(&env, &seed, index, opponent.rerolls, i + 1));
+            }
+        }
+        let result = dice.clone();
+        state.reroll_entropy = Some(entropy);
+        opponent.pending_hold = None;
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(result)
+    }
+
+    /// Lock in the category a player's final dice are scored against.
+    /// No further rerolls are allowed afterwards.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the scoring player
+    /// * `category` - The category to score
+    ///
+    /// # Returns
+    /// * `u32` - The player's score in that category
+    pub fn choose_category(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        category: Category,
+    ) -> Result<u32, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, |config| config.rule == ScoringRule::Yahtzee)?;
+        if game.player1_dice.is_empty() {
+            return Err(Error::DiceNotRolled);
+        }
+
+        let (state, dice) = if player == game.player1 {
+            (&mut game.player1_reroll, &game.player1_dice)
+        } else if player == game.player2 {
+            (&mut game.player2_reroll, &game.player2_dice)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if state.score.is_some() {
+            return Err(Error::AlreadyScored);
+        }
+        if state.pending_hold.is_some() {
+            return Err(Error::RerollPending);
+        }
+        let score = score_category(dice, category);
+        state.score = Some(score);
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(score)
+    }
+
+    // ========================================================================
+    // Betting Games
+    // ========================================================================
+
+    /// Match the opponent's added stake and raise it by `points`, locking the
+    /// difference in the Game Hub. At most `MAX_RAISES` raises per game.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the raising player
+    /// * `points` - Points to raise by, on top of matching the opponent
+    pub fn raise(env: Env, session_id: u32, player: Address, points: i128) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_betting_turn(&env, &key, &player)?;
+
+        if points <= 0 || game.betting.raises >= MAX_RAISES {
+            return Err(Error::InvalidRaise);
+        }
+        let owed = owed_to_call(&game, &player);
+        let points = owed.checked_add(points).ok_or(Error::InvalidRaise)?;
+        add_stake(&env, session_id, &mut game, &player, points)?;
+        game.betting.raises += 1;
+        game.betting.actions += 1;
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Match the opponent's added stake (a check if nothing is owed). Betting
+    /// closes on any call except the opening check, after which both players
+    /// reveal their entropy.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the calling player
+    pub fn call(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_betting_turn(&env, &key, &player)?;
+
+        let owed = owed_to_call(&game, &player);
+        add_stake(&env, session_id, &mut game, &player, owed)?;
+        game.betting.closed = game.betting.actions > 0;
+        game.betting.actions += 1;
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Give up the game. The opponent wins immediately and the session is
+    /// settled through the Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the folding player
     ///
     /// # Returns
     /// * `Address` - Address of the winning player
+    pub fn fold(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_betting_turn(&env, &key, &player)?;
+
+        let winner = if player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        };
+        record_round(&env, session_id, &game, 0, &winner);
+        if winner == game.player1 {
+            game.player1_score += 1;
+        } else {
+            game.player2_score += 1;
+        }
+        game.betting.closed = true;
+        game.winner = Some(winner.clone());
+        env.storage().temporary().set(&key, &game);
+
+        end_game_with_hub(&env, session_id, winner == game.player1);
+
+        Ok(winner)
+    }
+
+    // ========================================================================
+    // Liar's Dice
+    // ========================================================================
+
+    /// Bid that at least `quantity` dice across both hands show `face`.
+    /// Players alternate bids, starting with player 1, and each bid must raise
+    /// the previous one.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the bidding player
+    /// * `quantity` - Number of dice claimed
+    /// * `face` - Face claimed
+    pub fn bid(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        quantity: u32,
+        face: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_liars_dice_turn(&env, &key, &player)?;
+
+        validate_bid(&game.config, game.bids.last(), quantity, face)?;
+        game.bids.push_back(Bid {
+            player,
+            quantity,
+            face,
+        });
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Challenge the previous bid. Both players must then reveal their
+    /// entropy before the winner can be revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the challenging player
+    pub fn call_liar(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_liars_dice_turn(&env, &key, &player)?;
+
+        if game.bids.is_empty() {
+            return Err(Error::NoBid);
+        }
+        game.challenger = Some(player);
+
+        restart_clock(&env, &mut game);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the winner of the current round and, once a player has won a
+    /// majority of rounds, submit the outcome to GameHub.
+    /// Can only be called after both players have rolled (in reroll games,
+    /// after both players have chosen a category; in Liar's Dice, after the
+    /// challenge and both reveals).
+    /// This generates dice rolls for both players, determines the round winner
+    /// (re-rolling ties), and either ends the session or starts the next round,
+    /// in which both players roll again.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Address` - Address of the round's winner (the match winner once the game has ended)
     pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
@@ -260,87 +1476,231 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
+        // Check if game already ended (has a winner, timed out as a draw or aborted)
         if let Some(winner) = &game.winner {
             return Ok(winner.clone());
         }
+        if game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
 
         // Check both players have rolled
         if !game.player1_rolled || !game.player2_rolled {
             return Err(Error::BothPlayersNotRolled);
         }
 
-        // Generate deterministic dice rolls (1-6)
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission, which would cause different winners.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Roll dice for both players using unique seeds
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 1]));
-        let player1_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 2]));
-        let player1_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 1]));
-        let player2_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 2]));
-        let player2_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        game.player1_die1 = Some(player1_die1);
-        game.player1_die2 = Some(player1_die2);
-        game.player2_die1 = Some(player2_die1);
-        game.player2_die2 = Some(player2_die2);
-
-        // Determine winner (if tie, player1 wins)
-        let player1_total = player1_die1 + player1_die2;
-        let player2_total = player2_die1 + player2_die2;
-        let winner = if player1_total >= player2_total {
+        // Determine the round winner under the game's scoring rule. Ties are
+        // re-rolled rather than awarded to either player.
+        let base_seed = game_base_seed(&env, session_id, &game);
+        let mut tie_rerolls = 0u32;
+        let player1_wins = match game.config.rule {
+            ScoringRule::Yahtzee => {
+                // Reroll games: dice are already final and each player has locked in a score
+                let (Some(score1), Some(score2)) =
+                    (game.player1_reroll.score, game.player2_reroll.score)
+                else {
+                    return Err(Error::BothPlayersNotScored);
+                };
+                if score1 != score2 {
+                    score1 > score2
+                } else {
+                    // Equal scores go to a roll-off, seeded after the last
+                    // possible reroll
+                    let seed = entropy_seed(&env, &base_seed, &game)?;
+                    let (player1_wins, roll_offs) = roll_off(&env, &seed, MAX_REROLLS + 1);
+                    tie_rerolls = roll_offs;
+                    player1_wins
+                }
+            }
+            ScoringRule::LiarsDice => {
+                // The last bid stands if enough revealed dice show its face
+                let (Some(bid), Some(challenger)) = (game.bids.last(), &game.challenger) else {
+                    return Err(Error::NoChallenge);
+                };
+                if game.player1_reroll.entropy.is_none() || game.player2_reroll.entropy.is_none() {
+                    return Err(Error::DiceNotRolled);
+                }
+                let shown = game
+                    .player1_dice
+                    .iter()
+                    .chain(game.player2_dice.iter())
+                    .filter(|die| *die == bid.face)
+                    .count() as u32;
+                let bid_stands = shown >= bid.quantity;
+                (bid_stands && bid.player == game.player1)
+                    || (!bid_stands && *challenger == game.player1)
+            }
+            _ if game.config.betting => {
+                // Betting games: all dice were rolled as entropy was revealed
+                let seed = entropy_seed(&env, &base_seed, &game)?;
+                let score1 = score_dice(&game.player1_dice, game.config.rule);
+                let score2 = score_dice(&game.player2_dice, game.config.rule);
+                if score1 != score2 {
+                    score1 > score2
+                } else {
+                    let (player1_wins, roll_offs) = roll_off(&env, &seed, 1);
+                    tie_rerolls = roll_offs;
+                    player1_wins
+                }
+            }
+            _ => loop {
+                // Generate deterministic dice rolls (1 to config.sides) for this
+                // round, re-rolling ties
+                let seed = round_seed(&env, &base_seed, game.round, tie_rerolls);
+                game.player1_dice = roll_dice(&env, &seed, 1, &game.config);
+                game.player2_dice = roll_dice(&env, &seed, 2, &game.config);
+                let score1 = score_dice(&game.player1_dice, game.config.rule);
+                let score2 = score_dice(&game.player2_dice, game.config.rule);
+                if score1 != score2 {
+                    break score1 > score2;
+                }
+                if tie_rerolls == MAX_TIE_REROLLS {
+                    break coin_flip(&env, &seed);
+                }
+                tie_rerolls += 1;
+            },
+        };
+        let round_winner = if player1_wins {
             game.player1.clone()
         } else {
             game.player2.clone()
         };
 
+        record_round(&env, session_id, &game, tie_rerolls, &round_winner);
+
+        // Update the running score; the match continues until a majority
+        if player1_wins {
+            game.player1_score += 1;
+        } else {
+            game.player2_score += 1;
+        }
+        let majority = game.config.best_of / 2 + 1;
+        if game.player1_score < majority && game.player2_score < majority {
+            game.round += 1;
+            game.player1_rolled = false;
+            game.player2_rolled = false;
+            restart_clock(&env, &mut game);
+            env.storage().temporary().set(&key, &game);
+            return Ok(round_winner);
+        }
+        let winner = round_winner;
+
         // Update game with winner (this marks the game as ended)
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
+        // Call GameHub to end the session
+        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
+        end_game_with_hub(&env, session_id, player1_won);
+
+        Ok(winner)
+    }
+
+    /// Settle a game whose deadline has passed while it waits on a player.
+    /// If only one player is still to act, the other wins the match by
+    /// forfeit; if both are, the game is settled as a draw. A game waiting
+    /// only on `reveal_winner` cannot be timed out. Anyone may call this.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The winning player, or `None` for a draw
+    pub fn claim_timeout(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
 
-        // Call GameHub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        let winner = match (awaiting(&game, true), awaiting(&game, false)) {
+            (false, false) => return Err(Error::NoIdlePlayer),
+            (true, true) => None,
+            (false, true) => Some(game.player1.clone()),
+            (true, false) => Some(game.player2.clone()),
+        };
+        match &winner {
+            Some(winner) => {
+                game.winner = Some(winner.clone());
+                env.storage().temporary().set(&key, &game);
+                end_game_with_hub(&env, session_id, *winner == game.player1);
+            }
+            None => {
+                game.drawn = true;
+                env.storage().temporary().set(&key, &game);
+
+                // Neither player wins; the Game Hub releases both players' points
+                let game_hub_addr: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::GameHubAddress)
+                    .expect("GameHub address not set");
+                let game_hub = GameHubClient::new(&env, &game_hub_addr);
+                game_hub.end_game_draw(&session_id);
+            }
+        }
 
         Ok(winner)
     }
 
+    /// Concede the match in any game mode. The opponent wins and the session
+    /// is settled through the Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the resigning player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, |_| true)?;
+
+        let winner = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        game.winner = Some(winner.clone());
+        env.storage().temporary().set(&key, &game);
+
+        end_game_with_hub(&env, session_id, winner == game.player1);
+
+        Ok(winner)
+    }
+
+    /// Cancel the game by agreement. Both players must sign; the session is
+    /// cancelled through the Game Hub with no result and all points refunded.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn abort(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game = load_game_for(&env, &key, |_| true)?;
+
+        game.player1.require_auth();
+        game.player2.require_auth();
+
+        game.aborted = true;
+        env.storage().temporary().set(&key, &game);
+
+        cancel_game_with_hub(&env, session_id);
+
+        Ok(())
+    }
+
     /// Get game information.
     ///
     /// # Arguments
@@ -356,6 +1716,25 @@ impl DiceDuelContract {
             .ok_or(Error::GameNotFound)
     }
 
+    /// Get the completed rounds of a game.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<RoundRecord>` - Completed rounds, oldest first
+    pub fn get_round_history(env: Env, session_id: u32) -> Result<Vec<RoundRecord>, Error> {
+        if !env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::GameNotFound);
+        }
+
+        Ok(env
+            .storage()
+            .temporary()
+            .get(&DataKey::RoundHistory(session_id))
+            .unwrap_or(Vec::new(&env)))
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
@@ -414,6 +1793,42 @@ impl DiceDuelContract {
             .set(&DataKey::GameHubAddress, &new_hub);
     }
 
+    /// Get the number of ledgers players in new games have to act before the
+    /// opponent can claim a timeout
+    ///
+    /// # Returns
+    /// * `u32` - The configured timeout
+    pub fn get_timeout_ledgers(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS)
+    }
+
+    /// Set the number of ledgers players in new games have to act before the
+    /// opponent can claim a timeout. Games already in progress keep their timeout.
+    ///
+    /// # Arguments
+    /// * `timeout_ledgers` - Ledgers allowed per action, 1 to `MAX_TIMEOUT_LEDGERS`
+    pub fn set_timeout_ledgers(env: Env, timeout_ledgers: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if timeout_ledgers == 0 || timeout_ledgers > MAX_TIMEOUT_LEDGERS {
+            return Err(Error::InvalidTimeout);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TimeoutLedgers, &timeout_ledgers);
+
+        Ok(())
+    }
+
     /// Update the contract WASM hash (upgrade contract)
     ///
     /// # Arguments
@@ -3,9 +3,12 @@
 // Unit tests for the dice-duel contract using a simple mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 
-use crate::{DiceDuelContract, DiceDuelContractClient, Error};
+use crate::{
+    entropy_seed, game_base_seed, hand_salt, reroll_seed, roll_hidden_dice, roll_reroll_die, score_category, score_dice, Category, DiceConfig,
+    DiceDuelContract, DiceDuelContractClient, Error, ScoringRule, MAX_TIMEOUT_LEDGERS,
+};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -32,6 +35,18 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn add_stake(_env: Env, _session_id: u32, _player: Address, _points: i128) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn cancel_game(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -83,6 +98,124 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// Two d6 scored by their sum
+fn classic_dice() -> DiceConfig {
+    DiceConfig {
+        dice_count: 2,
+        sides: 6,
+        rule: ScoringRule::Sum,
+        best_of: 1,
+        betting: false,
+    }
+}
+
+/// Five d6 with up to two rerolls, scored by category
+fn reroll_dice() -> DiceConfig {
+    DiceConfig {
+        dice_count: 5,
+        sides: 6,
+        rule: ScoringRule::Yahtzee,
+        best_of: 1,
+        betting: false,
+    }
+}
+
+fn entropy_commitment(env: &Env, entropy: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(entropy.clone())).into()
+}
+
+/// Hash a secret into a reroll-game entropy chain: the opening entropy,
+/// followed by the links that answer the opponent's two rerolls
+fn entropy_chain(env: &Env, secret: u8) -> [BytesN<32>; 3] {
+    let link2 = BytesN::from_array(env, &[secret; 32]);
+    let link1 = entropy_commitment(env, &link2);
+    [entropy_commitment(env, &link1), link1, link2]
+}
+
+/// Start a reroll game and have both players commit and reveal entropy.
+/// Returns each player's entropy chain.
+fn start_reroll_game(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) -> ([BytesN<32>; 3], [BytesN<32>; 3]) {
+    let points = 100_0000000;
+    client.start_game(&session_id, player1, player2, &points, &points, &reroll_dice());
+
+    let chain1 = entropy_chain(env, 1);
+    let chain2 = entropy_chain(env, 2);
+    client.commit_entropy(&session_id, player1, &entropy_commitment(env, &chain1[0]));
+    client.commit_entropy(&session_id, player2, &entropy_commitment(env, &chain2[0]));
+    client.reveal_entropy(&session_id, player1, &chain1[0]);
+    client.reveal_entropy(&session_id, player2, &chain2[0]);
+    (chain1, chain2)
+}
+
+/// Two d6 per player, hidden until the challenge
+fn liars_dice() -> DiceConfig {
+    DiceConfig {
+        dice_count: 2,
+        sides: 6,
+        rule: ScoringRule::LiarsDice,
+        best_of: 1,
+        betting: false,
+    }
+}
+
+/// Start a Liar's Dice game with both players' entropy committed and both
+/// salts revealed
+fn start_liars_dice_game(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    config: &DiceConfig,
+) -> (BytesN<32>, BytesN<32>) {
+    let points = 100_0000000;
+    client.start_game(&session_id, player1, player2, &points, &points, config);
+
+    let entropy1 = BytesN::from_array(env, &[3u8; 32]);
+    let entropy2 = BytesN::from_array(env, &[4u8; 32]);
+    let (salt1, salt2) = (entropy_commitment(env, &entropy1), entropy_commitment(env, &entropy2));
+    client.commit_entropy(&session_id, player1, &entropy_commitment(env, &salt1));
+    client.commit_entropy(&session_id, player2, &entropy_commitment(env, &salt2));
+    client.reveal_salt(&session_id, player1, &salt1);
+    client.reveal_salt(&session_id, player2, &salt2);
+    (entropy1, entropy2)
+}
+
+/// Two d6, betting after the first die is seen
+fn betting_dice() -> DiceConfig {
+    DiceConfig {
+        dice_count: 2,
+        sides: 6,
+        rule: ScoringRule::Sum,
+        best_of: 1,
+        betting: true,
+    }
+}
+
+/// Start a betting game with both players' entropy committed
+fn start_betting_game(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) -> (BytesN<32>, BytesN<32>) {
+    let points = 100_0000000;
+    client.start_game(&session_id, player1, player2, &points, &points, &betting_dice());
+
+    let entropy1 = BytesN::from_array(env, &[5u8; 32]);
+    let entropy2 = BytesN::from_array(env, &[6u8; 32]);
+    client.commit_entropy(&session_id, player1, &entropy_commitment(env, &entropy1));
+    client.commit_entropy(&session_id, player2, &entropy_commitment(env, &entropy2));
+    (entropy1, entropy2)
+}
+
 /// Assert that a Result contains a specific dice_duel error
 fn assert_dice_duel_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
@@ -129,7 +262,7 @@ fn test_complete_game() {
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &classic_dice());
 
     // Verify initial state
     let game = client.get_game(&session_id);
@@ -138,12 +271,11 @@ fn test_complete_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
-    assert!(game.player1_die1.is_none());
-    assert!(game.player1_die2.is_none());
-    assert!(game.player2_die1.is_none());
-    assert!(game.player2_die2.is_none());
+    assert!(!game.player1_rolled);
+    assert!(!game.player2_rolled);
+    assert_eq!(game.config, classic_dice());
+    assert!(game.player1_dice.is_empty());
+    assert!(game.player2_dice.is_empty());
 
     // Players roll
     client.roll(&session_id, &player1);
@@ -158,18 +290,14 @@ fn test_complete_game() {
     assert!(final_game.winner.is_some());
     assert_eq!(final_game.winner.unwrap(), winner);
 
-    let p1d1 = final_game.player1_die1.unwrap();
-    let p1d2 = final_game.player1_die2.unwrap();
-    let p2d1 = final_game.player2_die1.unwrap();
-    let p2d2 = final_game.player2_die2.unwrap();
-
-    assert!((1..=6).contains(&p1d1));
-    assert!((1..=6).contains(&p1d2));
-    assert!((1..=6).contains(&p2d1));
-    assert!((1..=6).contains(&p2d2));
+    assert_eq!(final_game.player1_dice.len(), 2);
+    assert_eq!(final_game.player2_dice.len(), 2);
+    for die in final_game.player1_dice.iter().chain(final_game.player2_dice.iter()) {
+        assert!((1..=6).contains(&die));
+    }
 
-    let total1 = p1d1 + p1d2;
-    let total2 = p2d1 + p2d2;
+    let total1: u32 = final_game.player1_dice.iter().sum();
+    let total2: u32 = final_game.player2_dice.iter().sum();
     assert!((2..=12).contains(&total1));
     assert!((2..=12).contains(&total2));
 }
@@ -181,7 +309,7 @@ fn test_cannot_roll_twice() {
     let session_id = 2u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &classic_dice());
 
     client.roll(&session_id, &player1);
     let result = client.try_roll(&session_id, &player1);
@@ -195,7 +323,7 @@ fn test_cannot_reveal_before_both_roll() {
     let session_id = 3u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &classic_dice());
     client.roll(&session_id, &player1);
 
     let result = client.try_reveal_winner(&session_id);
@@ -209,7 +337,7 @@ fn test_non_player_cannot_roll() {
     let session_id = 4u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &classic_dice());
 
     let non_player = Address::generate(&_env);
     let result = client.try_roll(&session_id, &non_player);
@@ -223,12 +351,936 @@ fn test_cannot_roll_after_game_ended() {
     let session_id = 5u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &classic_dice());
+    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player2);
+    client.reveal_winner(&session_id);
+
+    let result = client.try_roll(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Dice Configuration Tests
+// ============================================================================
+
+#[test]
+fn test_custom_dice_rolled_in_range() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 10u32;
+    let points = 100_0000000;
+    let config = DiceConfig {
+        dice_count: 5,
+        sides: 20,
+        rule: ScoringRule::HighestDie,
+        best_of: 1,
+        betting: false,
+    };
+
+    client.start_game(&session_id, &player1, &player2, &points, &points, &config);
+    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player2);
+    let winner = client.reveal_winner(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_dice.len(), 5);
+    assert_eq!(game.player2_dice.len(), 5);
+    for die in game.player1_dice.iter().chain(game.player2_dice.iter()) {
+        assert!((1..=20).contains(&die));
+    }
+
+    let best1 = game.player1_dice.iter().max().unwrap();
+    let best2 = game.player2_dice.iter().max().unwrap();
+    let expected = if best1 > best2 { player1 } else { player2 };
+    assert_eq!(winner, expected);
+}
+
+#[test]
+fn test_invalid_dice_config_rejected() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let points = 100_0000000;
+    let config = |dice_count, sides, rule, best_of| DiceConfig {
+        dice_count,
+        sides,
+        rule,
+        best_of,
+        betting: false,
+    };
+    let invalid = [
+        config(0, 6, ScoringRule::Sum, 1),
+        config(11, 6, ScoringRule::Sum, 1),
+        config(2, 3, ScoringRule::Sum, 1),
+        config(2, 21, ScoringRule::Sum, 1),
+        // Craps is only defined for two d6
+        config(3, 6, ScoringRule::Craps, 1),
+        config(2, 8, ScoringRule::Craps, 1),
+        // Matches are an odd number of rounds, and entropy games a single round
+        config(2, 6, ScoringRule::Sum, 0),
+        config(2, 6, ScoringRule::Sum, 4),
+        config(2, 6, ScoringRule::Sum, 11),
+        config(2, 6, ScoringRule::LiarsDice, 3),
+    ];
+
+    for (i, config) in invalid.iter().enumerate() {
+        let result =
+            client.try_start_game(&(20 + i as u32), &player1, &player2, &points, &points, config);
+        assert_dice_duel_error(&result, Error::InvalidConfig);
+    }
+}
+
+#[test]
+fn test_scoring_rules() {
+    let env = Env::default();
+
+    // Sum and highest die
+    assert_eq!(score_dice(&vec![&env, 3, 5, 2], ScoringRule::Sum), 10);
+    assert_eq!(score_dice(&vec![&env, 3, 5, 2], ScoringRule::HighestDie), 5);
+
+    // Pairs beat sums: any pair beats a higher total without one
+    let pair = score_dice(&vec![&env, 1, 1, 2], ScoringRule::PairsBeatSums);
+    let no_pair = score_dice(&vec![&env, 4, 5, 6], ScoringRule::PairsBeatSums);
+    assert!(pair > no_pair);
+    // Three of a kind beats a higher pair; a higher pair beats a lower pair
+    let trips = score_dice(&vec![&env, 2, 2, 2], ScoringRule::PairsBeatSums);
+    let high_pair = score_dice(&vec![&env, 6, 6, 1], ScoringRule::PairsBeatSums);
+    assert!(trips > high_pair);
+    assert!(high_pair > pair);
+    // Equal pairs fall back to the total
+    let pair_kicker = score_dice(&vec![&env, 1, 1, 5], ScoringRule::PairsBeatSums);
+    assert!(pair_kicker > pair);
+
+    // Craps: naturals beat points, points beat craps
+    let natural = score_dice(&vec![&env, 5, 6], ScoringRule::Craps);
+    let seven = score_dice(&vec![&env, 3, 4], ScoringRule::Craps);
+    let point = score_dice(&vec![&env, 5, 5], ScoringRule::Craps);
+    let craps = score_dice(&vec![&env, 6, 6], ScoringRule::Craps);
+    assert!(natural > point);
+    assert!(seven > point);
+    assert!(point > craps);
+    assert!(score_dice(&vec![&env, 2, 2], ScoringRule::Craps) > craps);
+}
+
+// ============================================================================
+// Best-of-N Match Tests
+// ============================================================================
+
+#[test]
+fn test_best_of_three_match() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    let points = 100_0000000;
+    let config = DiceConfig {
+        dice_count: 2,
+        sides: 6,
+        rule: ScoringRule::Sum,
+        best_of: 3,
+        betting: false,
+    };
+    client.start_game(&session_id, &player1, &player2, &points, &points, &config);
+
+    let mut rounds = 0u32;
+    while client.get_game(&session_id).winner.is_none() {
+        rounds += 1;
+        assert_eq!(client.get_game(&session_id).round, rounds);
+        client.roll(&session_id, &player1);
+        client.roll(&session_id, &player2);
+        let round_winner = client.reveal_winner(&session_id);
+
+        let record = client.get_round_history(&session_id).last().unwrap();
+        assert_eq!(record.round, rounds);
+        assert_eq!(record.winner, round_winner);
+    }
+
+    let game = client.get_game(&session_id);
+    assert!((2..=3).contains(&rounds));
+    assert_eq!(game.player1_score.max(game.player2_score), 2);
+    assert_eq!(game.player1_score + game.player2_score, rounds);
+    let expected = if game.player1_score == 2 { player1.clone() } else { player2.clone() };
+    assert_eq!(game.winner, Some(expected.clone()));
+    assert_eq!(client.get_round_history(&session_id).len(), rounds);
+
+    // Further reveals return the match winner
+    assert_eq!(client.reveal_winner(&session_id), expected);
+}
+
+#[test]
+fn test_rounds_need_fresh_rolls() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 31u32;
+    let points = 100_0000000;
+    let config = DiceConfig {
+        dice_count: 2,
+        sides: 6,
+        rule: ScoringRule::Sum,
+        best_of: 3,
+        betting: false,
+    };
+    client.start_game(&session_id, &player1, &player2, &points, &points, &config);
     client.roll(&session_id, &player1);
     client.roll(&session_id, &player2);
     client.reveal_winner(&session_id);
 
+    // The next round needs both players to roll again
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert!(!game.player1_rolled && !game.player2_rolled);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+}
+
+#[test]
+fn test_tied_rounds_are_rerolled() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    // A single d4 ties often; find a session whose round tied at least once
+    let points = 100_0000000;
+    let config = DiceConfig {
+        dice_count: 1,
+        sides: 4,
+        rule: ScoringRule::HighestDie,
+        best_of: 1,
+        betting: false,
+    };
+    let mut found_tie = false;
+    for session_id in 100u32..150 {
+        client.start_game(&session_id, &player1, &player2, &points, &points, &config);
+        client.roll(&session_id, &player1);
+        client.roll(&session_id, &player2);
+        let winner = client.reveal_winner(&session_id);
+
+        // The recorded dice are the final, untied roll and decide the winner
+        let record = client.get_round_history(&session_id).get(0).unwrap();
+        let die1 = record.player1_dice.get(0).unwrap();
+        let die2 = record.player2_dice.get(0).unwrap();
+        assert_ne!(die1, die2);
+        assert_eq!(winner, if die1 > die2 { player1.clone() } else { player2.clone() });
+
+        if record.tie_rerolls > 0 {
+            found_tie = true;
+            break;
+        }
+    }
+    assert!(found_tie, "expected at least one tied round in 50 sessions");
+}
+
+// ============================================================================
+// Reroll Game Tests
+// ============================================================================
+
+#[test]
+fn test_reroll_game_flow() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    let (_chain1, chain2) = start_reroll_game(&env, &client, session_id, &player1, &player2);
+
+    let opening = client.get_game(&session_id).player1_dice;
+    assert_eq!(opening.len(), 5);
+    for die in opening.iter() {
+        assert!((1..=6).contains(&die));
+    }
+
+    // Held dice (0 and 2) never change across rerolls
+    client.reroll(&session_id, &player1, &0b00101);
+    let first = client.answer_reroll(&session_id, &player2, &chain2[1]);
+    assert_eq!(first.get(0), opening.get(0));
+    assert_eq!(first.get(2), opening.get(2));
+    client.reroll(&session_id, &player1, &0b00101);
+    let second = client.answer_reroll(&session_id, &player2, &chain2[2]);
+    assert_eq!(second.get(0), opening.get(0));
+    assert_eq!(second.get(2), opening.get(2));
+    assert_eq!(client.get_game(&session_id).player1_reroll.rerolls, 2);
+
+    let result = client.try_reroll(&session_id, &player1, &0);
+    assert_dice_duel_error(&result, Error::NoRerollsLeft);
+
+    // Both players score, then the higher category score wins
+    let score1 = client.choose_category(&session_id, &player1, &Category::Chance);
+    let score2 = client.choose_category(&session_id, &player2, &Category::Sixes);
+    let game = client.get_game(&session_id);
+    assert_eq!(score1, score_category(&game.player1_dice, Category::Chance));
+    assert_eq!(score2, score_category(&game.player2_dice, Category::Sixes));
+
+    // Equal scores would go to a roll-off
+    let winner = client.reveal_winner(&session_id);
+    if score1 != score2 {
+        let expected = if score1 > score2 { player1 } else { player2 };
+        assert_eq!(winner, expected);
+    }
+}
+
+#[test]
+fn test_rerolls_wait_for_opponent_entropy() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 47u32;
+    let (chain1, chain2) = start_reroll_game(&env, &client, session_id, &player1, &player2);
+    let game = client.get_game(&session_id);
+
+    // Nothing is rolled until the opponent answers, so the new dice are
+    // unknown while choosing what to hold
+    client.reroll(&session_id, &player1, &0);
+    let pending = client.get_game(&session_id);
+    assert_eq!(pending.player1_dice, game.player1_dice);
+    assert_eq!(pending.player1_reroll.pending_hold, Some(0));
+    let result = client.try_reroll(&session_id, &player1, &0);
+    assert_dice_duel_error(&result, Error::RerollPending);
+    let result = client.try_choose_category(&session_id, &player1, &Category::Chance);
+    assert_dice_duel_error(&result, Error::RerollPending);
+
+    // Only the opponent answers, with the next link of their committed chain
+    let result = client.try_answer_reroll(&session_id, &player1, &chain1[1]);
+    assert_dice_duel_error(&result, Error::NoPendingReroll);
+    let result = client.try_answer_reroll(&session_id, &player2, &chain2[2]);
+    assert_dice_duel_error(&result, Error::InvalidEntropy);
+    let rerolled = client.answer_reroll(&session_id, &player2, &chain2[1]);
+
+    // The dice follow from the entropy seed, the answer and the reroll index
+    let expected = env.as_contract(&client.address, || {
+        let base_seed = game_base_seed(&env, session_id, &game);
+        let seed = reroll_seed(&env, &entropy_seed(&env, &base_seed, &game).unwrap(), &chain2[1]);
+        let mut dice = Vec::new(&env);
+        for n in 1..=5 {
+            dice.push_back(roll_reroll_die(&env, &seed, 1, 1, n));
+        }
+        dice
+    });
+    assert_eq!(rerolled, expected);
+    assert_eq!(client.get_game(&session_id).player1_dice, rerolled);
+    assert!(client.get_game(&session_id).player1_reroll.pending_hold.is_none());
+}
+
+#[test]
+fn test_reroll_requires_both_reveals() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    let points = 100_0000000;
+    client.start_game(&session_id, &player1, &player2, &points, &points, &reroll_dice());
+
+    let entropy1 = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_entropy(&session_id, &player1, &entropy_commitment(&env, &entropy1));
+
+    // Cannot reveal until both players have committed
+    let result = client.try_reveal_entropy(&session_id, &player1, &entropy1);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+
+    let entropy2 = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_entropy(&session_id, &player2, &entropy_commitment(&env, &entropy2));
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+
+    // No dice until both players have revealed
+    let result = client.try_reroll(&session_id, &player1, &0);
+    assert_dice_duel_error(&result, Error::DiceNotRolled);
+    let result = client.try_choose_category(&session_id, &player1, &Category::Chance);
+    assert_dice_duel_error(&result, Error::DiceNotRolled);
+    let result = client.try_answer_reroll(&session_id, &player2, &entropy2);
+    assert_dice_duel_error(&result, Error::DiceNotRolled);
+}
+
+#[test]
+fn test_reveal_entropy_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    let points = 100_0000000;
+    client.start_game(&session_id, &player1, &player2, &points, &points, &reroll_dice());
+
+    let entropy1 = BytesN::from_array(&env, &[1u8; 32]);
+    let entropy2 = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_entropy(&session_id, &player1, &entropy_commitment(&env, &entropy1));
+    client.commit_entropy(&session_id, &player2, &entropy_commitment(&env, &entropy2));
+
+    let result = client.try_reveal_entropy(&session_id, &player1, &entropy2);
+    assert_dice_duel_error(&result, Error::InvalidEntropy);
+}
+
+#[test]
+fn test_reroll_rules_enforced() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    start_reroll_game(&env, &client, session_id, &player1, &player2);
+
+    let result = client.try_reroll(&session_id, &player1, &0b100000);
+    assert_dice_duel_error(&result, Error::InvalidHoldMask);
+
+    // Winner cannot be revealed until both players score
+    client.choose_category(&session_id, &player1, &Category::Chance);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotScored);
+
+    // Scoring ends a player's turn
+    let result = client.try_reroll(&session_id, &player1, &0);
+    assert_dice_duel_error(&result, Error::AlreadyScored);
+    let result = client.try_choose_category(&session_id, &player1, &Category::Ones);
+    assert_dice_duel_error(&result, Error::AlreadyScored);
+}
+
+#[test]
+fn test_reroll_and_classic_entrypoints_are_separate() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let points = 100_0000000;
+    client.start_game(&44u32, &player1, &player2, &points, &points, &reroll_dice());
+    let result = client.try_roll(&44u32, &player1);
+    assert_dice_duel_error(&result, Error::WrongGameMode);
+
+    client.start_game(&45u32, &player1, &player2, &points, &points, &classic_dice());
+    let commitment = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_commit_entropy(&45u32, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::WrongGameMode);
+
+    // Reroll games are always five d6
+    let config = DiceConfig {
+        dice_count: 6,
+        sides: 6,
+        rule: ScoringRule::Yahtzee,
+        best_of: 1,
+        betting: false,
+    };
+    let result = client.try_start_game(&46u32, &player1, &player2, &points, &points, &config);
+    assert_dice_duel_error(&result, Error::InvalidConfig);
+}
+
+#[test]
+fn test_score_categories() {
+    let env = Env::default();
+
+    let full_house = vec![&env, 3, 3, 5, 5, 5];
+    assert_eq!(score_category(&full_house, Category::Threes), 6);
+    assert_eq!(score_category(&full_house, Category::Fives), 15);
+    assert_eq!(score_category(&full_house, Category::ThreeOfAKind), 21);
+    assert_eq!(score_category(&full_house, Category::FourOfAKind), 0);
+    assert_eq!(score_category(&full_house, Category::FullHouse), 25);
+    assert_eq!(score_category(&full_house, Category::Chance), 21);
+
+    let small = vec![&env, 1, 2, 3, 4, 6];
+    assert_eq!(score_category(&small, Category::SmallStraight), 30);
+    assert_eq!(score_category(&small, Category::LargeStraight), 0);
+
+    let large = vec![&env, 6, 5, 4, 3, 2];
+    assert_eq!(score_category(&large, Category::SmallStraight), 30);
+    assert_eq!(score_category(&large, Category::LargeStraight), 40);
+
+    let yahtzee = vec![&env, 4, 4, 4, 4, 4];
+    assert_eq!(score_category(&yahtzee, Category::Yahtzee), 50);
+    assert_eq!(score_category(&yahtzee, Category::FourOfAKind), 20);
+    assert_eq!(score_category(&yahtzee, Category::FullHouse), 0);
+    assert_eq!(score_category(&full_house, Category::Yahtzee), 0);
+}
+
+// ============================================================================
+// Liar's Dice Tests
+// ============================================================================
+
+#[test]
+fn test_liars_dice_challenge_settles_against_revealed_dice() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 60u32;
+    let (entropy1, entropy2) =
+        start_liars_dice_game(&env, &client, session_id, &player1, &player2, &liars_dice());
+
+    client.bid(&session_id, &player1, &1, &3);
+    client.bid(&session_id, &player2, &2, &3);
+    client.call_liar(&session_id, &player1);
+
+    // Dice stay hidden until each player reveals
+    assert!(client.get_game(&session_id).player1_dice.is_empty());
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+    client.reveal_entropy(&session_id, &player2, &entropy2);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.bids.len(), 2);
+    assert_eq!(game.challenger, Some(player1.clone()));
+    assert_eq!(game.player1_dice.len(), 2);
+    assert_eq!(game.player2_dice.len(), 2);
+
+    let threes = game
+        .player1_dice
+        .iter()
+        .chain(game.player2_dice.iter())
+        .filter(|die| *die == 3)
+        .count();
+    let expected = if threes >= 2 { player2 } else { player1 };
+    assert_eq!(client.reveal_winner(&session_id), expected);
+}
+
+#[test]
+fn test_hidden_dice_depend_on_opponent_salt() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 69u32;
+    let config = DiceConfig {
+        dice_count: 5,
+        ..liars_dice()
+    };
+    client.start_game(&session_id, &player1, &player2, &100, &100, &config);
+    let entropy1 = BytesN::from_array(&env, &[3u8; 32]);
+    let entropy2 = BytesN::from_array(&env, &[4u8; 32]);
+    let (salt1, salt2) = (entropy_commitment(&env, &entropy1), entropy_commitment(&env, &entropy2));
+    client.commit_entropy(&session_id, &player1, &entropy_commitment(&env, &salt1));
+
+    // Salts are only revealed once both commitments are fixed
+    let result = client.try_reveal_salt(&session_id, &player1, &salt1);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+    client.commit_entropy(&session_id, &player2, &entropy_commitment(&env, &salt2));
+    let result = client.try_reveal_salt(&session_id, &player2, &salt1);
+    assert_dice_duel_error(&result, Error::InvalidEntropy);
+
+    // Bidding waits for both salts, so no hand exists until then
+    client.reveal_salt(&session_id, &player1, &salt1);
+    assert!(client.get_game(&session_id).hand_salt.is_none());
+    let result = client.try_bid(&session_id, &player1, &1, &1);
+    assert_dice_duel_error(&result, Error::DiceNotRolled);
+    client.reveal_salt(&session_id, &player2, &salt2);
+
+    let game = client.get_game(&session_id);
+    let salt = game.hand_salt.clone().unwrap();
+    client.bid(&session_id, &player1, &1, &1);
+    client.call_liar(&session_id, &player2);
+
+    // Entropy must match the revealed salt
+    let result = client.try_reveal_entropy(&session_id, &player1, &salt1);
+    assert_dice_duel_error(&result, Error::InvalidEntropy);
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+    let revealed = client.get_game(&session_id).player1_dice;
+
+    let other_salt = entropy_commitment(&env, &BytesN::from_array(&env, &[9u8; 32]));
+    env.as_contract(&client.address, || {
+        // The hand salt is fixed by both commitments, and player 1 can derive
+        // their own hand from it before the reveal
+        assert_eq!(salt, hand_salt(&env, &salt1, &salt2));
+        let base_seed = game_base_seed(&env, session_id, &game);
+        let hand = roll_hidden_dice(&env, &base_seed, &salt, &entropy1, &config);
+        assert_eq!(hand, revealed);
+
+        // The same entropy against a different opponent salt rolls a
+        // different hand, so it cannot be searched for before committing
+        let other = hand_salt(&env, &salt1, &other_salt);
+        assert_ne!(roll_hidden_dice(&env, &base_seed, &other, &entropy1, &config), hand);
+    });
+}
+
+#[test]
+fn test_liars_dice_impossible_bid_loses_challenge() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 61u32;
+    let config = DiceConfig {
+        dice_count: 10,
+        sides: 20,
+        rule: ScoringRule::LiarsDice,
+        best_of: 1,
+        betting: false,
+    };
+    let (entropy1, entropy2) =
+        start_liars_dice_game(&env, &client, session_id, &player1, &player2, &config);
+
+    // Claiming all twenty dice show the same face is (deterministically) false
+    client.bid(&session_id, &player1, &20, &20);
+    client.call_liar(&session_id, &player2);
+    client.reveal_entropy(&session_id, &player2, &entropy2);
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+
+    assert_eq!(client.reveal_winner(&session_id), player2);
+}
+
+#[test]
+fn test_liars_dice_bids_must_raise_in_turn() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 62u32;
+    start_liars_dice_game(&env, &client, session_id, &player1, &player2, &liars_dice());
+
+    // Player 1 bids first, and cannot call before any bid
+    let result = client.try_bid(&session_id, &player2, &1, &2);
+    assert_dice_duel_error(&result, Error::NotYourTurn);
+    let result = client.try_call_liar(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::NoBid);
+
+    // Bids must be in range
+    let result = client.try_bid(&session_id, &player1, &0, &2);
+    assert_dice_duel_error(&result, Error::InvalidBid);
+    let result = client.try_bid(&session_id, &player1, &5, &2);
+    assert_dice_duel_error(&result, Error::InvalidBid);
+    let result = client.try_bid(&session_id, &player1, &1, &7);
+    assert_dice_duel_error(&result, Error::InvalidBid);
+
+    client.bid(&session_id, &player1, &2, &3);
+    let result = client.try_bid(&session_id, &player1, &3, &3);
+    assert_dice_duel_error(&result, Error::NotYourTurn);
+
+    // Same quantity needs a higher face; a higher quantity may use any face
+    let result = client.try_bid(&session_id, &player2, &2, &3);
+    assert_dice_duel_error(&result, Error::InvalidBid);
+    let result = client.try_bid(&session_id, &player2, &1, &6);
+    assert_dice_duel_error(&result, Error::InvalidBid);
+    client.bid(&session_id, &player2, &2, &4);
+    client.bid(&session_id, &player1, &3, &1);
+
+    // No more bids once challenged
+    client.call_liar(&session_id, &player2);
+    let result = client.try_bid(&session_id, &player1, &4, &1);
+    assert_dice_duel_error(&result, Error::AlreadyChallenged);
+}
+
+#[test]
+fn test_liars_dice_reveal_rules() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 63u32;
+    let (entropy1, _entropy2) =
+        start_liars_dice_game(&env, &client, session_id, &player1, &player2, &liars_dice());
+
+    // Revealing before the challenge would expose hidden dice
+    let result = client.try_reveal_entropy(&session_id, &player1, &entropy1);
+    assert_dice_duel_error(&result, Error::NoChallenge);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::NoChallenge);
+
+    client.bid(&session_id, &player1, &1, &6);
+    client.call_liar(&session_id, &player2);
+
+    let result = client.try_reveal_entropy(&session_id, &player2, &entropy1);
+    assert_dice_duel_error(&result, Error::InvalidEntropy);
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::DiceNotRolled);
+
+    // Reroll entrypoints do not apply
+    let result = client.try_reroll(&session_id, &player1, &0);
+    assert_dice_duel_error(&result, Error::WrongGameMode);
+}
+
+// ============================================================================
+// Betting Game Tests
+// ============================================================================
+
+#[test]
+fn test_betting_game_flow() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 70u32;
+    let (entropy1, entropy2) =
+        start_betting_game(&env, &client, session_id, &player1, &player2);
+
+    // Only the first die is rolled before betting
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_dice.len(), 1);
+    assert_eq!(game.player2_dice.len(), 1);
+
+    // Player 2 re-raises by 30, matching player 1's 50 first
+    client.raise(&session_id, &player1, &50);
+    client.raise(&session_id, &player2, &30);
+    client.call(&session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert!(game.betting.closed);
+    assert_eq!(game.betting.raises, 2);
+    assert_eq!(game.player1_points, 100_0000000 + 80);
+    assert_eq!(game.player2_points, 100_0000000 + 80);
+
+    // Hidden dice are rolled once both players reveal
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+    client.reveal_entropy(&session_id, &player2, &entropy2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_dice.len(), 2);
+    assert_eq!(game.player2_dice.len(), 2);
+
+    let total1: u32 = game.player1_dice.iter().sum();
+    let total2: u32 = game.player2_dice.iter().sum();
+    let winner = client.reveal_winner(&session_id);
+    if total1 != total2 {
+        assert_eq!(winner, if total1 > total2 { player1 } else { player2 });
+    }
+}
+
+#[test]
+fn test_betting_closes_after_two_checks() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 71u32;
+    start_betting_game(&env, &client, session_id, &player1, &player2);
+
+    client.call(&session_id, &player1);
+    assert!(!client.get_game(&session_id).betting.closed);
+    client.call(&session_id, &player2);
+
+    let game = client.get_game(&session_id);
+    assert!(game.betting.closed);
+    assert_eq!(game.player1_points, 100_0000000);
+    assert_eq!(game.player2_points, 100_0000000);
+
+    let result = client.try_raise(&session_id, &player1, &10);
+    assert_dice_duel_error(&result, Error::BettingClosed);
+}
+
+#[test]
+fn test_fold_settles_immediately() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 72u32;
+    start_betting_game(&env, &client, session_id, &player1, &player2);
+
+    client.raise(&session_id, &player1, &100);
+    let winner = client.fold(&session_id, &player2);
+    assert_eq!(winner, player1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(game.player1_score, 1);
+    assert_eq!(client.get_round_history(&session_id).len(), 1);
+    assert_eq!(client.reveal_winner(&session_id), player1);
+
+    let result = client.try_call(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_betting_rules_enforced() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 73u32;
+    let points = 100_0000000;
+    client.start_game(&session_id, &player1, &player2, &points, &points, &betting_dice());
     let result = client.try_roll(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::WrongGameMode);
+    let result = client.try_raise(&session_id, &player1, &10);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+
+    let session_id = 74u32;
+    let (entropy1, _entropy2) =
+        start_betting_game(&env, &client, session_id, &player1, &player2);
+
+    // Hidden dice stay hidden while betting is open
+    let result = client.try_reveal_entropy(&session_id, &player1, &entropy1);
+    assert_dice_duel_error(&result, Error::BettingOpen);
+
+    let result = client.try_raise(&session_id, &player2, &10);
+    assert_dice_duel_error(&result, Error::NotYourTurn);
+    let result = client.try_raise(&session_id, &player1, &0);
+    assert_dice_duel_error(&result, Error::InvalidRaise);
+
+    // Raises that would overflow a stake are rejected, not panicked on
+    let result = client.try_raise(&session_id, &player1, &i128::MAX);
+    assert_dice_duel_error(&result, Error::InvalidRaise);
+
+    // Three raises at most
+    client.raise(&session_id, &player1, &10);
+    let result = client.try_raise(&session_id, &player2, &(i128::MAX - 5));
+    assert_dice_duel_error(&result, Error::InvalidRaise);
+    client.raise(&session_id, &player2, &10);
+    client.raise(&session_id, &player1, &10);
+    let result = client.try_raise(&session_id, &player2, &10);
+    assert_dice_duel_error(&result, Error::InvalidRaise);
+    client.call(&session_id, &player2);
+    assert_eq!(client.get_game(&session_id).player2_points, points + 30);
+
+    // Betting needs a hidden die and a single round, and isn't combined with
+    // entropy rules
+    let mut config = betting_dice();
+    config.dice_count = 1;
+    let result = client.try_start_game(&75u32, &player1, &player2, &points, &points, &config);
+    assert_dice_duel_error(&result, Error::InvalidConfig);
+    let mut config = betting_dice();
+    config.best_of = 3;
+    let result = client.try_start_game(&75u32, &player1, &player2, &points, &points, &config);
+    assert_dice_duel_error(&result, Error::InvalidConfig);
+    let mut config = betting_dice();
+    config.rule = ScoringRule::LiarsDice;
+    let result = client.try_start_game(&75u32, &player1, &player2, &points, &points, &config);
+    assert_dice_duel_error(&result, Error::InvalidConfig);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+/// Move the ledger sequence forward
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ledgers);
+}
+
+#[test]
+fn test_claim_timeout_awards_the_player_who_rolled() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 1u32;
+    let points = 100_0000000;
+    client.start_game(&session_id, &player1, &player2, &points, &points, &classic_dice());
+    let timeout = client.get_timeout_ledgers();
+
+    // Rolling restarts the clock for player 2
+    advance_ledgers(&env, 100);
+    client.roll(&session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.deadline, env.ledger().sequence() + timeout);
+
+    advance_ledgers(&env, timeout);
+    let result = client.try_claim_timeout(&session_id);
+    assert_dice_duel_error(&result, Error::DeadlineNotReached);
+
+    advance_ledgers(&env, 1);
+    assert_eq!(client.claim_timeout(&session_id), Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    // The game is over
+    let result = client.try_roll(&session_id, &player2);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_claim_timeout(&session_id);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_follows_the_turn() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let timeout = client.get_timeout_ledgers();
+
+    // Liar's Dice: player 2 owes a bid after player 1's opening bid
+    start_liars_dice_game(&env, &client, 10, &player1, &player2, &liars_dice());
+    client.bid(&10, &player1, &2, &3);
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&10), Some(player1.clone()));
+
+    // Betting: player 1 acts first
+    start_betting_game(&env, &client, 11, &player1, &player2);
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&11), Some(player2.clone()));
+
+    // Reroll: after both reveals, both players still owe a category
+    start_reroll_game(&env, &client, 12, &player1, &player2);
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&12), None);
+    let game = client.get_game(&12);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    let result = client.try_choose_category(&12, &player1, &Category::Chance);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&12);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+
+    // Reroll: a player who has scored still owes answers to the opponent's rerolls
+    start_reroll_game(&env, &client, 13, &player1, &player2);
+    client.choose_category(&13, &player2, &Category::Chance);
+    client.reroll(&13, &player1, &0);
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&13), Some(player1.clone()));
+}
+
+#[test]
+fn test_claim_timeout_draw_and_no_idle_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+    let timeout = client.get_timeout_ledgers();
+
+    // Neither player rolled: nobody is owed the win
+    client.start_game(&1, &player1, &player2, &points, &points, &classic_dice());
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&1), None);
+    assert!(client.get_game(&1).drawn);
+    let result = client.try_roll(&1, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+
+    // Both players rolled: the game is revealed, not timed out
+    client.start_game(&2, &player1, &player2, &points, &points, &classic_dice());
+    client.roll(&2, &player1);
+    client.roll(&2, &player2);
+    advance_ledgers(&env, timeout + 1);
+    let result = client.try_claim_timeout(&2);
+    assert_dice_duel_error(&result, Error::NoIdlePlayer);
+    client.reveal_winner(&2);
+}
+
+#[test]
+fn test_admin_sets_timeout_for_new_games() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+
+    client.start_game(&1, &player1, &player2, &points, &points, &classic_dice());
+    client.set_timeout_ledgers(&10);
+    assert_eq!(client.get_timeout_ledgers(), 10);
+    let result = client.try_set_timeout_ledgers(&0);
+    assert_dice_duel_error(&result, Error::InvalidTimeout);
+    let result = client.try_set_timeout_ledgers(&(MAX_TIMEOUT_LEDGERS + 1));
+    assert_dice_duel_error(&result, Error::InvalidTimeout);
+
+    // Games in progress keep the timeout they started with
+    client.start_game(&2, &player1, &player2, &points, &points, &classic_dice());
+    client.roll(&1, &player1);
+    client.roll(&2, &player1);
+    advance_ledgers(&env, 11);
+    let result = client.try_claim_timeout(&1);
+    assert_dice_duel_error(&result, Error::DeadlineNotReached);
+    assert_eq!(client.claim_timeout(&2), Some(player1.clone()));
+}
+
+// ============================================================================
+// Resign and Abort Tests
+// ============================================================================
+
+#[test]
+fn test_resign_ends_the_match_for_the_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+
+    // Resigning mid-match ends it, whatever the score
+    let mut config = classic_dice();
+    config.best_of = 3;
+    client.start_game(&1, &player1, &player2, &points, &points, &config);
+    client.roll(&1, &player1);
+    client.roll(&1, &player2);
+    client.reveal_winner(&1);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&1, &outsider);
+    assert_dice_duel_error(&result, Error::NotPlayer);
+
+    assert_eq!(client.resign(&1, &player2), player1);
+    assert_eq!(env.auths()[0].0, player2);
+    assert_eq!(client.get_game(&1).winner, Some(player1.clone()));
+    let result = client.try_roll(&1, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+
+    // Entropy games can be resigned out of turn too
+    start_liars_dice_game(&env, &client, 2, &player1, &player2, &liars_dice());
+    assert_eq!(client.resign(&2, &player2), player1);
+    let result = client.try_bid(&2, &player1, &2, &3);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_abort_needs_both_players_and_ends_the_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_betting_game(&env, &client, 1, &player1, &player2);
+    client.raise(&1, &player1, &10);
+
+    client.abort(&1);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2, "abort must be signed by both players");
+    assert_eq!(auths[0].0, player1);
+    assert_eq!(auths[1].0, player2);
+
+    let game = client.get_game(&1);
+    assert!(game.aborted);
+    assert!(game.winner.is_none());
+    let result = client.try_call(&1, &player2);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&1, &player2);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_abort(&1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    advance_ledgers(&env, client.get_timeout_ledgers() + 1);
+    let result = client.try_claim_timeout(&1);
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
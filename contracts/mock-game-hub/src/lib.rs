@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractevent, contractimpl, Address, Env, Vec};
 
 /// Mock Game Hub contract for game studio development
 ///
@@ -26,6 +26,45 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct GameCancelled {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct SideBetSettled {
+    pub session_id: u32,
+    pub player: Address,
+    pub wager: i128,
+    pub payout: i128,
+}
+
+#[contractevent]
+pub struct StakeAdded {
+    pub session_id: u32,
+    pub player: Address,
+    pub points: i128,
+}
+
+#[contractevent]
+pub struct TableGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub points: Vec<i128>,
+}
+
+#[contractevent]
+pub struct TableGameEnded {
+    pub session_id: u32,
+    pub ranks: Vec<u32>,
+}
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -77,6 +116,94 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// End a game session with no winner (both players keep their points)
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn end_game_draw(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameDrawn { session_id }.publish(&env);
+    }
+
+    /// Cancel a game session with no result (all locked points are refunded)
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being cancelled
+    pub fn cancel_game(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameCancelled { session_id }.publish(&env);
+    }
+
+    /// Lock additional points for a player in a running game session
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session the points are added to
+    /// * `player` - Address of the player raising their stake
+    /// * `points` - Additional points to lock (ignored in mock)
+    pub fn add_stake(env: Env, session_id: u32, player: Address, points: i128) {
+        // No auth required for mock
+        StakeAdded {
+            session_id,
+            player,
+            points,
+        }
+        .publish(&env);
+    }
+
+    /// Start a game session with more than two players
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `players` - Addresses of all players, in seat order
+    /// * `points` - Points amount per player, in seat order (ignored in mock)
+    pub fn start_table_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    ) {
+        // No auth required for mock
+        TableGameStarted {
+            session_id,
+            game_id,
+            players,
+            points,
+        }
+        .publish(&env);
+        // bump instance ttl if required
+        env.storage().instance().extend_ttl(17_280, 518_400);
+    }
+
+    /// End a game session with more than two players
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `ranks` - Rank of each player in seat order (1 = best, ties share a rank)
+    pub fn end_table_game(env: Env, session_id: u32, ranks: Vec<u32>) {
+        // No auth required for mock
+        TableGameEnded { session_id, ranks }.publish(&env);
+    }
+
+    /// Settle a side bet placed within a game session
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session the bet belongs to
+    /// * `player` - Address of the player who placed the bet
+    /// * `wager` - Points wagered (ignored in mock)
+    /// * `payout` - Total points returned to the player, 0 if the bet lost (ignored in mock)
+    pub fn settle_side_bet(env: Env, session_id: u32, player: Address, wager: i128, payout: i128) {
+        // No auth required for mock
+        SideBetSettled {
+            session_id,
+            player,
+            wager,
+            payout,
+        }
+        .publish(&env);
+    }
 }
 
 #[cfg(test)]
@@ -86,12 +213,80 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.end_game(&1, &true);
+    }
+
+    #[test]
+    fn test_start_and_draw_game() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.end_game_draw(&1);
+    }
+
+    #[test]
+    fn test_start_and_cancel_game() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.cancel_game(&1);
+    }
+
+    #[test]
+    fn test_add_stake() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.add_stake(&1, &player1, &500);
+        client.add_stake(&1, &player2, &500);
+        client.end_game(&1, &false);
+    }
+
+    #[test]
+    fn test_start_and_end_table_game() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let players = soroban_sdk::vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env)
+        ];
+        let points = soroban_sdk::vec![&env, 1000i128, 1000, 1000];
+        client.start_table_game(&game_id, &1, &players, &points);
+        client.end_table_game(&1, &soroban_sdk::vec![&env, 1u32, 2, 2]);
+    }
+
+    #[test]
+    fn test_settle_side_bet() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
         let player2 = Address::generate(&env);
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.settle_side_bet(&1, &player1, &100, &700);
         client.end_game(&1, &true);
     }
 }
@@ -32,6 +32,10 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn cancel_game(env: Env, session_id: u32);
 }
 
 // ============================================================================
@@ -47,6 +51,9 @@ pub enum Error {
     AlreadyGuessed = 3,
     BothPlayersNotGuessed = 4,
     GameAlreadyEnded = 5,
+    DeadlineNotReached = 6,
+    NoIdlePlayer = 7,
+    InvalidTimeout = 8,
 }
 
 // ============================================================================
@@ -64,6 +71,10 @@ pub struct Game {
     pub player2_guess: Option<u32>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
+    pub drawn: bool, // Settled as a draw because neither player guessed in time
+    pub timeout_ledgers: u32, // Ledgers each player has to act after the last action
+    pub deadline: u32, // Ledger after which an idle player can be timed out
+    pub aborted: bool, // Cancelled by both players; all points refunded
 }
 
 #[contracttype]
@@ -72,6 +83,7 @@ pub enum DataKey {
     Game(u32),
     GameHubAddress,
     Admin,
+    TimeoutLedgers,
 }
 
 // ============================================================================
@@ -84,6 +96,15 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers a player has to act before the opponent can claim a timeout
+/// (~1 day), unless the admin configures a different timeout via
+/// `set_timeout_ledgers`
+const DEFAULT_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Longest timeout `set_timeout_ledgers` accepts: a game's storage does not
+/// outlive `GAME_TTL_LEDGERS`, so a longer deadline could never be claimed
+const MAX_TIMEOUT_LEDGERS: u32 = GAME_TTL_LEDGERS;
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -156,6 +177,13 @@ impl NumberGuessContract {
             &player2_points,
         );
 
+        // Snapshot the timeout so admin changes don't affect games in progress
+        let timeout_ledgers: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS);
+
         // Create game (winning_number not set yet - will be generated in reveal_winner)
         let game = Game {
             player1: player1.clone(),
@@ -166,6 +194,10 @@ impl NumberGuessContract {
             player2_guess: None,
             winning_number: None,
             winner: None,
+            drawn: false,
+            aborted: false,
+            timeout_ledgers,
+            deadline: env.ledger().sequence() + timeout_ledgers,
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -193,7 +225,7 @@ impl NumberGuessContract {
         player.require_auth();
 
         // Validate guess is in range
-        if guess < 1 || guess > 10 {
+        if !(1..=10).contains(&guess) {
             panic!("Guess must be between 1 and 10");
         }
 
@@ -205,8 +237,8 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner, not drawn and not aborted)
+        if game.winner.is_some() || game.drawn || game.aborted {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -225,6 +257,9 @@ impl NumberGuessContract {
             return Err(Error::NotPlayer);
         }
 
+        // Every action restarts the clock for the player still to act
+        game.deadline = env.ledger().sequence() + game.timeout_ledgers;
+
         // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
 
@@ -251,10 +286,13 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
+        // Check if game already ended (has a winner, timed out as a draw or aborted)
         if let Some(winner) = &game.winner {
             return Ok(winner.clone());
         }
+        if game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
 
         // Check both players have guessed
         let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
@@ -293,17 +331,8 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
-
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
+        let distance1 = guess1.abs_diff(winning_number);
+        let distance2 = guess2.abs_diff(winning_number);
 
         // Determine winner (if equal distance, player1 wins)
         let winner = if distance1 <= distance2 {
@@ -335,6 +364,138 @@ impl NumberGuessContract {
         Ok(winner)
     }
 
+    /// Settle a game whose deadline has passed with a player still to guess.
+    /// The player who guessed wins by forfeit; if neither guessed, the game is
+    /// settled as a draw. Once both have guessed, use `reveal_winner` instead.
+    /// Anyone may call this.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The winning player, or `None` for a draw
+    pub fn claim_timeout(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+        let winner = match (game.player1_guess, game.player2_guess) {
+            (Some(_), Some(_)) => return Err(Error::NoIdlePlayer),
+            (None, None) => None,
+            (Some(_), None) => Some(game.player1.clone()),
+            (None, Some(_)) => Some(game.player2.clone()),
+        };
+        match &winner {
+            Some(winner) => {
+                game.winner = Some(winner.clone());
+                game_hub.end_game(&session_id, &(*winner == game.player1));
+            }
+            None => {
+                // Neither player wins; the Game Hub releases both players' points
+                game.drawn = true;
+                game_hub.end_game_draw(&session_id);
+            }
+        }
+        env.storage().temporary().set(&key, &game);
+
+        Ok(winner)
+    }
+
+    /// Concede the game. The opponent wins and the session ends through the
+    /// Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the resigning player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let winner = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        game.winner = Some(winner.clone());
+        env.storage().temporary().set(&key, &game);
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.end_game(&session_id, &(winner == game.player1));
+
+        Ok(winner)
+    }
+
+    /// Cancel the game by agreement. Both players must sign; the session is
+    /// cancelled through the Game Hub with no result and all points refunded.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn abort(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.player1.require_auth();
+        game.player2.require_auth();
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        game.aborted = true;
+        env.storage().temporary().set(&key, &game);
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.cancel_game(&session_id);
+
+        Ok(())
+    }
+
     /// Get game information.
     ///
     /// # Arguments
@@ -408,6 +569,42 @@ impl NumberGuessContract {
             .set(&DataKey::GameHubAddress, &new_hub);
     }
 
+    /// Get the number of ledgers players in new games have to act before the
+    /// opponent can claim a timeout
+    ///
+    /// # Returns
+    /// * `u32` - The configured timeout
+    pub fn get_timeout_ledgers(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS)
+    }
+
+    /// Set the number of ledgers players in new games have to act before the
+    /// opponent can claim a timeout. Games already in progress keep their timeout.
+    ///
+    /// # Arguments
+    /// * `timeout_ledgers` - Ledgers allowed per action, 1 to `MAX_TIMEOUT_LEDGERS`
+    pub fn set_timeout_ledgers(env: Env, timeout_ledgers: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if timeout_ledgers == 0 || timeout_ledgers > MAX_TIMEOUT_LEDGERS {
+            return Err(Error::InvalidTimeout);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TimeoutLedgers, &timeout_ledgers);
+
+        Ok(())
+    }
+
     /// Update the contract WASM hash (upgrade contract)
     ///
     /// # Arguments
@@ -6,7 +6,7 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{Error, NumberGuessContract, NumberGuessContractClient, MAX_TIMEOUT_LEDGERS};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
 
@@ -35,6 +35,14 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn cancel_game(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -176,7 +184,7 @@ fn test_complete_game() {
     assert_eq!(final_game.winner.unwrap(), winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
@@ -196,7 +204,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -244,16 +252,8 @@ fn test_closest_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
 
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
@@ -298,16 +298,8 @@ fn test_exact_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
     } else {
@@ -510,6 +502,149 @@ fn test_asymmetric_points() {
     assert!(final_game.winner.is_some()); // Game has ended
 }
 
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+/// Move the ledger sequence forward
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ledgers);
+}
+
+#[test]
+fn test_claim_timeout_awards_the_player_who_guessed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    let timeout = client.get_timeout_ledgers();
+
+    // Guessing restarts the clock for player 2
+    advance_ledgers(&env, 100);
+    client.make_guess(&session_id, &player1, &5);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.deadline, env.ledger().sequence() + timeout);
+
+    advance_ledgers(&env, timeout);
+    let result = client.try_claim_timeout(&session_id);
+    assert_number_guess_error(&result, Error::DeadlineNotReached);
+
+    advance_ledgers(&env, 1);
+    assert_eq!(client.claim_timeout(&session_id), Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    // The game is over
+    let result = client.try_make_guess(&session_id, &player2, &5);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_claim_timeout(&session_id);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_draw_and_no_idle_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let timeout = client.get_timeout_ledgers();
+
+    // Neither player guessed: nobody is owed the win
+    client.start_game(&1, &player1, &player2, &100, &100);
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&1), None);
+    let game = client.get_game(&1);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    let result = client.try_make_guess(&1, &player1, &5);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+
+    // Both players guessed: the game is revealed, not timed out
+    client.start_game(&2, &player1, &player2, &100, &100);
+    client.make_guess(&2, &player1, &5);
+    client.make_guess(&2, &player2, &7);
+    advance_ledgers(&env, timeout + 1);
+    let result = client.try_claim_timeout(&2);
+    assert_number_guess_error(&result, Error::NoIdlePlayer);
+    client.reveal_winner(&2);
+}
+
+#[test]
+fn test_admin_sets_timeout_for_new_games() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &100);
+    let result = client.try_set_timeout_ledgers(&0);
+    assert_number_guess_error(&result, Error::InvalidTimeout);
+    let result = client.try_set_timeout_ledgers(&(MAX_TIMEOUT_LEDGERS + 1));
+    assert_number_guess_error(&result, Error::InvalidTimeout);
+    client.set_timeout_ledgers(&10);
+    assert_eq!(client.get_timeout_ledgers(), 10);
+
+    // Games in progress keep the timeout they started with
+    client.start_game(&2, &player1, &player2, &100, &100);
+    client.make_guess(&1, &player1, &5);
+    client.make_guess(&2, &player1, &5);
+    advance_ledgers(&env, 11);
+    let result = client.try_claim_timeout(&1);
+    assert_number_guess_error(&result, Error::DeadlineNotReached);
+    assert_eq!(client.claim_timeout(&2), Some(player1));
+}
+
+// ============================================================================
+// Resign and Abort Tests
+// ============================================================================
+
+#[test]
+fn test_resign_awards_the_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &100);
+    client.make_guess(&1, &player1, &5);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&1, &outsider);
+    assert_number_guess_error(&result, Error::NotPlayer);
+
+    assert_eq!(client.resign(&1, &player1), player2);
+    assert_eq!(env.auths()[0].0, player1);
+    assert_eq!(client.get_game(&1).winner, Some(player2.clone()));
+
+    // The game is over
+    let result = client.try_make_guess(&1, &player2, &5);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&1, &player2);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_abort_needs_both_players_and_ends_the_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &100);
+    client.make_guess(&1, &player1, &5);
+
+    client.abort(&1);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2, "abort must be signed by both players");
+    assert_eq!(auths[0].0, player1);
+    assert_eq!(auths[1].0, player2);
+
+    let game = client.get_game(&1);
+    assert!(game.aborted);
+    assert!(game.winner.is_none());
+    let result = client.try_make_guess(&1, &player2, &5);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&1, &player2);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_abort(&1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    advance_ledgers(&env, client.get_timeout_ledgers() + 1);
+    let result = client.try_claim_timeout(&1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
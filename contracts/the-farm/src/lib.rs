@@ -3,10 +3,28 @@
 //! The Farm – ZK Dungeon (scaffold)
 //!
 //! Minimal lobby + progression contract wired to the Game Hub.
-//! ZK verification is stubbed for now (will be replaced by a Noir verifier call).
+//! Door attempts carry a Groth16 proof over BLS12-381, checked on-chain with
+//! Soroban's pairing host functions against a verifying key set by the admin.
+//! The proof's public inputs are (lobby_id, floor, nonce, door, opened, layout
+//! commit), so a proof cannot be replayed on another lobby, floor or attempt.
+//!
+//! Each player's commitment fixes the dungeon their opponent climbs: the
+//! correct door on every floor is derived from the secret behind it (see
+//! [`layout_door`]). A player names a door with `attempt_door` and the layout's
+//! owner answers with `answer_door`, proving whether that door opens
+//! (`opened`). Nobody holds the secret to the layout they climb, and both
+//! clears and failures are verified; the derivation lives here so the circuit
+//! and the contract agree on it.
+//!
+//! Items lying on floors (keys, torches, traps) are picked up on clearing them
+//! and spent with `use_item`; their effects are resolved with the next attempt.
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, Address, BytesN, Env,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
+    crypto::bls12_381::{Fr, G1Affine, G2Affine},
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, Vec, U256,
 };
 
 // ── Game Hub client (existing testnet hub) ────────────────────────────────────
@@ -22,6 +40,8 @@ pub trait GameHub {
         player2_points: i128,
     );
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn end_game_draw(env: Env, session_id: u32);
+    fn cancel_game(env: Env, session_id: u32);
 }
 
 // ── Errors ───────────────────────────────────────────────────────────────────
@@ -39,6 +59,22 @@ pub enum Error {
     NotWaiting = 8,
     GameFinished = 9,
     MissingCommit = 10,
+    VerifyingKeyNotSet = 11,
+    InvalidVerifyingKey = 12,
+    InvalidCommit = 13,
+    LobbyExists = 14,
+    InvalidDoor = 15,
+    LobbyExpired = 16,
+    NotExpired = 17,
+    InvalidStake = 18,
+    StakeMismatch = 19,
+    InvalidConfig = 20,
+    InvalidProof = 21,
+    CoolingDown = 22,
+    NoItem = 23,
+    InvalidItemUse = 24,
+    AttemptPending = 25,
+    NoPendingAttempt = 26,
 }
 
 // ── Types ────────────────────────────────────────────────────────────────────
@@ -48,6 +84,63 @@ pub enum Status {
     Waiting,
     Active,
     Finished,
+    /// Waiting lobby withdrawn by its creator
+    Cancelled,
+    /// Active lobby settled after a player stopped acting
+    Abandoned,
+    /// Active lobby cancelled by both players, stakes refunded
+    Aborted,
+}
+
+/// Items found in the dungeon
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Item {
+    /// Opens the door on the player's current floor without a proof
+    Key,
+    /// Lights the floor: the player's next verified wrong door carries no
+    /// penalty. It reveals nothing else about the layout.
+    Torch,
+    /// Set on the opponent's next floor, where it catches their first clear
+    Trap,
+}
+
+/// An item lying on a floor; each player picks it up the first time they
+/// clear that floor
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ItemGrant {
+    pub floor: u32,
+    pub item: Item,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Inventory {
+    pub keys: u32,
+    pub torches: u32,
+    pub traps: u32,
+}
+
+/// Dungeon shape, fixed at lobby creation
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DungeonConfig {
+    /// Number of floors, 1..=MAX_DEPTH; clearing the last one wins
+    pub depth: u32,
+    /// Cooperative gate floors in ascending order: both players must clear a
+    /// gate floor before either moves past it. The last floor decides the race
+    /// and cannot be a gate.
+    pub gate_floors: Vec<u32>,
+    /// Failed attempts allowed on one floor before the player forfeits; 0 = no limit
+    pub max_failures: u32,
+    /// Whether a failed attempt knocks the player back one floor. Gates are
+    /// checkpoints: nobody is knocked off or onto a gate floor.
+    pub knockback: bool,
+    /// Ledgers a player must wait after a failed attempt; 0 = no cooldown
+    pub cooldown_ledgers: u32,
+    /// Items placed on floors below the last one
+    pub item_grants: Vec<ItemGrant>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -56,8 +149,26 @@ pub struct PlayerState {
     pub commit: Option<BytesN<32>>,
     pub floor: u32,
     pub last_nonce: u32,
-    pub cleared_gate1: bool,
-    pub cleared_gate5: bool,
+    /// Door tried on the current floor with `last_nonce`, awaiting the
+    /// opponent's answer
+    pub pending_door: Option<u32>,
+    /// Gate floors this player has cleared; bit `floor - 1` is set per floor
+    pub cleared_gates: u32,
+    /// Ledger of the player's last commit or door attempt
+    pub last_active: u32,
+    /// Verified failed attempts over the whole game
+    pub failed_attempts: u32,
+    /// Verified failed attempts on the current floor
+    pub floor_failures: u32,
+    /// First ledger the player may attempt a door again after a failure
+    pub cooldown_until: u32,
+    pub inventory: Inventory,
+    /// Floors whose items this player has picked up; bit `floor - 1` per floor
+    pub looted_floors: u32,
+    /// Floors the opponent has trapped for this player; bit `floor - 1` per floor
+    pub trapped_floors: u32,
+    /// Whether a torch protects the player's next failed attempt
+    pub torch_lit: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -68,7 +179,79 @@ pub struct Lobby {
     pub player2: Option<Address>,
     pub p1: PlayerState,
     pub p2: PlayerState,
+    pub config: DungeonConfig,
     pub winner: Option<Address>,
+    /// Points each player locks in the hub session
+    pub stake: i128,
+    /// Ledger the lobby was created in
+    pub created_ledger: u32,
+}
+
+/// Entry in the open lobby index, enough to filter lobbies without loading them
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OpenLobby {
+    pub lobby_id: u32,
+    pub player1: Address,
+    pub stake: i128,
+    pub depth: u32,
+    pub created_ledger: u32,
+}
+
+/// A door attempt awaiting the layout owner's answer
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DoorAttempt {
+    pub floor: u32,
+    pub nonce: u32,
+    pub door: u32,
+}
+
+/// Public view of a player's progress: no commit, nonce or inventory
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerProgress {
+    pub floor: u32,
+    /// Gate floors cleared; bit `floor - 1` is set per floor
+    pub cleared_gates: u32,
+    pub committed: bool,
+}
+
+/// Public view of a lobby, safe to serve to spectators
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LobbyProgress {
+    pub status: Status,
+    pub player1: Address,
+    pub player2: Option<Address>,
+    pub p1: PlayerProgress,
+    pub p2: PlayerProgress,
+    pub depth: u32,
+    pub gate_floors: Vec<u32>,
+    pub winner: Option<Address>,
+    pub stake: i128,
+}
+
+/// Groth16 verifying key over BLS12-381. `ic` holds one point per public
+/// input plus the constant term.
+#[derive(Clone)]
+#[contracttype]
+pub struct VerifyingKey {
+    pub alpha: G1Affine,
+    pub beta: G2Affine,
+    pub gamma: G2Affine,
+    pub delta: G2Affine,
+    pub ic: Vec<G1Affine>,
+}
+
+/// Groth16 proof of whether the attempted door is the correct one for the
+/// layout owner's committed layout
+#[derive(Clone)]
+#[contracttype]
+pub struct Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
 }
 
 #[derive(Clone)]
@@ -77,10 +260,397 @@ pub enum DataKey {
     Lobby(u32),
     Admin,
     Hub,
+    VerifyingKey,
+    LobbyNonce,
+    OpenLobbies,
+}
+
+// ── Events ───────────────────────────────────────────────────────────────────
+#[contractevent]
+pub struct LobbyCreated {
+    pub lobby_id: u32,
+    pub player1: Address,
+    pub stake: i128,
 }
 
 // ── Constants ────────────────────────────────────────────────────────────────
-const MAX_FLOOR: u32 = 10;
+/// Deepest supported dungeon; per-floor flags are tracked in u32 bitmaps
+pub const MAX_DEPTH: u32 = 32;
+/// Doors on every floor; exactly one of them is correct
+pub const DOORS_PER_FLOOR: u32 = 3;
+/// TTL for lobby storage (30 days in ledgers, ~5 seconds per ledger)
+const LOBBY_TTL_LEDGERS: u32 = 518_400;
+/// Ledgers a Waiting lobby stays joinable before its creator may cancel it (~1 day)
+const WAITING_EXPIRY_LEDGERS: u32 = 17_280;
+/// Ledgers an Active lobby may go without a move before it can be settled as
+/// abandoned (~1 day)
+const ABANDON_LEDGERS: u32 = 17_280;
+/// Longest post-failure cooldown a dungeon may set (~2 hours), far below
+/// `ABANDON_LEDGERS` so a cooling player is never settled as abandoned
+pub const MAX_COOLDOWN_LEDGERS: u32 = 1_440;
+/// Waiting lobbies the open lobby index holds at once; lobbies created while
+/// it is full are not listed but can still be joined by id
+pub const MAX_OPEN_LOBBIES: u32 = 100;
+/// Lobbies one creator may have in the open lobby index at once, so a single
+/// account cannot crowd everyone else out of it
+pub const MAX_OPEN_LOBBIES_PER_CREATOR: u32 = 3;
+/// Largest page `list_open_lobbies` returns
+pub const MAX_LOBBY_PAGE: u32 = 50;
+/// Public inputs of the door circuit: lobby_id, floor, nonce, door, opened, layout commit
+const PUBLIC_INPUTS: u32 = 6;
+/// Order of the BLS12-381 scalar field; commits must be canonical field elements
+const FR_MODULUS: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+// ── Storage ──────────────────────────────────────────────────────────────────
+fn load_lobby(env: &Env, lobby_id: u32) -> Result<Lobby, Error> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::Lobby(lobby_id))
+        .ok_or(Error::LobbyNotFound)
+}
+
+/// Store a lobby and extend its TTL, so a lobby in use never expires mid-game
+fn save_lobby(env: &Env, lobby_id: u32, lobby: &Lobby) {
+    let key = DataKey::Lobby(lobby_id);
+    env.storage().temporary().set(&key, lobby);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, LOBBY_TTL_LEDGERS, LOBBY_TTL_LEDGERS);
+}
+
+/// Load the open lobby index, leaving out lobbies that expired unjoined
+fn load_open_lobbies(env: &Env) -> Vec<OpenLobby> {
+    let stored: Vec<OpenLobby> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::OpenLobbies)
+        .unwrap_or(Vec::new(env));
+    let mut open = Vec::new(env);
+    for entry in stored.iter() {
+        if !expired_since(env, entry.created_ledger) {
+            open.push_back(entry);
+        }
+    }
+    open
+}
+
+fn save_open_lobbies(env: &Env, open: &Vec<OpenLobby>) {
+    let key = DataKey::OpenLobbies;
+    env.storage().temporary().set(&key, open);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, LOBBY_TTL_LEDGERS, LOBBY_TTL_LEDGERS);
+}
+
+/// Take a lobby out of the open lobby index once it can no longer be joined
+fn close_open_lobby(env: &Env, lobby_id: u32) {
+    let mut open = load_open_lobbies(env);
+    if let Some(index) = open.iter().position(|entry| entry.lobby_id == lobby_id) {
+        open.remove(index as u32);
+    }
+    save_open_lobbies(env, &open);
+}
+
+fn validate_config(config: &DungeonConfig) -> Result<(), Error> {
+    if config.depth == 0 || config.depth > MAX_DEPTH {
+        return Err(Error::InvalidConfig);
+    }
+    let mut previous = 0;
+    for floor in config.gate_floors.iter() {
+        if floor <= previous || floor >= config.depth {
+            return Err(Error::InvalidConfig);
+        }
+        previous = floor;
+    }
+    for grant in config.item_grants.iter() {
+        if grant.floor == 0 || grant.floor >= config.depth {
+            return Err(Error::InvalidConfig);
+        }
+    }
+    if config.cooldown_ledgers > MAX_COOLDOWN_LEDGERS {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
+fn floor_bit(floor: u32) -> u32 {
+    1 << (floor - 1)
+}
+
+// ── Progression ──────────────────────────────────────────────────────────────
+/// What a verified door clear does to the players' floors
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Transition {
+    /// Ordinary floor: the player moves up alone
+    Advance,
+    /// Gate floor the other player has not cleared yet: the player stays put
+    WaitAtGate,
+    /// Gate floor the other player already cleared: both move up together
+    OpenGate,
+    /// Final floor: the player leaves the dungeon and wins
+    Escape,
+    /// Floor trapped by the other player: the trap is sprung and the player stays put
+    Trapped,
+}
+
+/// Apply a verified failed attempt: record it, start the cooldown and knock the
+/// player back if configured. Returns true if the player has now used up the
+/// floor's failure allowance and forfeits.
+/// A lit torch absorbs the failure instead: it is only recorded.
+fn penalize(env: &Env, config: &DungeonConfig, me: &mut PlayerState) -> bool {
+    me.failed_attempts += 1;
+    if me.torch_lit {
+        me.torch_lit = false;
+        return false;
+    }
+    me.floor_failures += 1;
+    if config.cooldown_ledgers > 0 {
+        me.cooldown_until = env
+            .ledger()
+            .sequence()
+            .saturating_add(config.cooldown_ledgers);
+    }
+    if config.max_failures > 0 && me.floor_failures >= config.max_failures {
+        return true;
+    }
+    let floor = me.floor;
+    if config.knockback
+        && floor > 1
+        && !config.gate_floors.contains(floor)
+        && !config.gate_floors.contains(floor - 1)
+    {
+        me.floor = floor - 1;
+    }
+    false
+}
+
+/// Apply a verified clear of `me.floor`. Only `Escape` finishes the game, and
+/// only from the final floor, which is never a gate.
+fn clear_floor(
+    config: &DungeonConfig,
+    me: &mut PlayerState,
+    other: &mut PlayerState,
+) -> Transition {
+    let floor = me.floor;
+    if config.gate_floors.contains(floor) {
+        me.cleared_gates |= floor_bit(floor);
+        if other.cleared_gates & floor_bit(floor) == 0 {
+            return Transition::WaitAtGate;
+        }
+        // The other player is waiting on this gate, so they are on it too
+        me.floor = floor + 1;
+        other.floor = floor + 1;
+        Transition::OpenGate
+    } else if floor == config.depth {
+        me.floor = floor + 1;
+        Transition::Escape
+    } else {
+        me.floor = floor + 1;
+        Transition::Advance
+    }
+}
+
+/// Apply a verified clear or a key on `me.floor`, unless a trap there catches it
+fn open_door(config: &DungeonConfig, me: &mut PlayerState, other: &mut PlayerState) -> Transition {
+    let bit = floor_bit(me.floor);
+    if me.trapped_floors & bit != 0 {
+        me.trapped_floors &= !bit;
+        return Transition::Trapped;
+    }
+    clear_floor(config, me, other)
+}
+
+/// Pick up the items on `floor` the first time the player clears it
+fn loot(config: &DungeonConfig, me: &mut PlayerState, floor: u32) {
+    if me.looted_floors & floor_bit(floor) != 0 {
+        return;
+    }
+    me.looted_floors |= floor_bit(floor);
+    for grant in config.item_grants.iter() {
+        if grant.floor == floor {
+            match grant.item {
+                Item::Key => me.inventory.keys += 1,
+                Item::Torch => me.inventory.torches += 1,
+                Item::Trap => me.inventory.traps += 1,
+            }
+        }
+    }
+}
+
+/// Settle a player's move away from `from`: loot it if they went up, and
+/// reset the failure allowance, which is per floor. An attempt still pending
+/// on `from` is dropped; its nonce stays spent.
+fn settle_move(config: &DungeonConfig, me: &mut PlayerState, from: u32) {
+    if me.floor > from {
+        loot(config, me, from);
+    }
+    if me.floor != from {
+        me.floor_failures = 0;
+        me.pending_door = None;
+    }
+}
+
+/// Whether the game is waiting on `me`: to answer the opponent's pending
+/// attempt, or to attempt a door themselves. A player whose own attempt is
+/// pending, or who has cleared the gate floor they stand on, is waiting on
+/// the opponent instead.
+fn owes_move(config: &DungeonConfig, me: &PlayerState, other: &PlayerState) -> bool {
+    if other.pending_door.is_some() {
+        return true;
+    }
+    let waiting_at_gate =
+        config.gate_floors.contains(me.floor) && me.cleared_gates & floor_bit(me.floor) != 0;
+    me.pending_door.is_none() && !waiting_at_gate
+}
+
+/// Split a lobby into (caller is player 1, caller's state, opponent's state)
+fn player_states(
+    lobby: &Lobby,
+    player: &Address,
+) -> Result<(bool, PlayerState, PlayerState), Error> {
+    if *player == lobby.player1 {
+        Ok((true, lobby.p1.clone(), lobby.p2.clone()))
+    } else if Some(player.clone()) == lobby.player2 {
+        Ok((false, lobby.p2.clone(), lobby.p1.clone()))
+    } else {
+        Err(Error::NotPlayer)
+    }
+}
+
+/// Write a turn's player states back and save the lobby, ending the hub
+/// session if the turn decided the game (`Some(caller_won)`)
+fn finish_turn(
+    env: &Env,
+    lobby_id: u32,
+    mut lobby: Lobby,
+    caller_is_p1: bool,
+    me: PlayerState,
+    other: PlayerState,
+    decided: Option<bool>,
+) {
+    if let Some(caller_won) = decided {
+        lobby.status = Status::Finished;
+        let player1_won = caller_won == caller_is_p1;
+        lobby.winner = if player1_won {
+            Some(lobby.player1.clone())
+        } else {
+            lobby.player2.clone()
+        };
+        hub_client(env).end_game(&lobby_id, &player1_won);
+    }
+    if caller_is_p1 {
+        lobby.p1 = me;
+        lobby.p2 = other;
+    } else {
+        lobby.p2 = me;
+        lobby.p1 = other;
+    }
+    save_lobby(env, lobby_id, &lobby);
+}
+
+fn progress(state: &PlayerState) -> PlayerProgress {
+    PlayerProgress {
+        floor: state.floor,
+        cleared_gates: state.cleared_gates,
+        committed: state.commit.is_some(),
+    }
+}
+
+fn waiting_expired(env: &Env, lobby: &Lobby) -> bool {
+    expired_since(env, lobby.created_ledger)
+}
+
+/// Whether a Waiting lobby created at `created_ledger` has expired
+fn expired_since(env: &Env, created_ledger: u32) -> bool {
+    env.ledger().sequence() > created_ledger + WAITING_EXPIRY_LEDGERS
+}
+
+fn hub_client(env: &Env) -> GameHubClient<'_> {
+    let hub: Address = env.storage().instance().get(&DataKey::Hub).unwrap();
+    GameHubClient::new(env, &hub)
+}
+
+// ── Layout ───────────────────────────────────────────────────────────────────
+const COMMIT_DOMAIN: &[u8] = b"the-farm/commit";
+const DOOR_DOMAIN: &[u8] = b"the-farm/door";
+
+/// Commitment to a layout secret: sha256(COMMIT_DOMAIN ‖ secret) with the top
+/// byte cleared, so it is always a canonical BLS12-381 scalar.
+pub fn layout_commitment(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, COMMIT_DOMAIN);
+    preimage.append(&secret.clone().into());
+    let mut digest = env.crypto().sha256(&preimage).to_array();
+    digest[0] = 0;
+    BytesN::from_array(env, &digest)
+}
+
+/// Correct door on `floor` for a layout secret:
+/// first 4 bytes of sha256(DOOR_DOMAIN ‖ secret ‖ floor) mod DOORS_PER_FLOOR.
+pub fn layout_door(env: &Env, secret: &BytesN<32>, floor: u32) -> u32 {
+    let mut preimage = Bytes::from_slice(env, DOOR_DOMAIN);
+    preimage.append(&secret.clone().into());
+    preimage.extend_from_array(&floor.to_be_bytes());
+    let digest = env.crypto().sha256(&preimage).to_array();
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) % DOORS_PER_FLOOR
+}
+
+// ── Verifier ─────────────────────────────────────────────────────────────────
+/// Check a Groth16 proof:
+/// e(-A, B) · e(alpha, beta) · e(vk_x, gamma) · e(C, delta) == 1
+/// where vk_x = ic[0] + Σ inputs[i] · ic[i + 1].
+fn verify_groth16(env: &Env, vk: &VerifyingKey, proof: &Proof, inputs: Vec<Fr>) -> bool {
+    if vk.ic.len() != inputs.len() + 1 {
+        return false;
+    }
+    let bls = env.crypto().bls12_381();
+
+    let mut vk_x = vk.ic.get(0).unwrap();
+    for (i, input) in inputs.iter().enumerate() {
+        let term = bls.g1_mul(&vk.ic.get(i as u32 + 1).unwrap(), &input);
+        vk_x = bls.g1_add(&vk_x, &term);
+    }
+
+    let vp1 = Vec::from_array(
+        env,
+        [-proof.a.clone(), vk.alpha.clone(), vk_x, proof.c.clone()],
+    );
+    let vp2 = Vec::from_array(
+        env,
+        [
+            proof.b.clone(),
+            vk.beta.clone(),
+            vk.gamma.clone(),
+            vk.delta.clone(),
+        ],
+    );
+    bls.pairing_check(vp1, vp2)
+}
+
+/// Public inputs binding a door proof to one attempt
+fn door_inputs(
+    env: &Env,
+    lobby_id: u32,
+    floor: u32,
+    nonce: u32,
+    door: u32,
+    opened: bool,
+    commit: &BytesN<32>,
+) -> Vec<Fr> {
+    Vec::from_array(
+        env,
+        [
+            Fr::from_u256(U256::from_u32(env, lobby_id)),
+            Fr::from_u256(U256::from_u32(env, floor)),
+            Fr::from_u256(U256::from_u32(env, nonce)),
+            Fr::from_u256(U256::from_u32(env, door)),
+            Fr::from_u256(U256::from_u32(env, opened as u32)),
+            Fr::from_bytes(commit.clone()),
+        ],
+    )
+}
 
 // ── Contract ─────────────────────────────────────────────────────────────────
 #[contract]
@@ -96,11 +666,50 @@ impl TheFarm {
             .set(&DataKey::Hub, &game_hub);
     }
 
-    // Create a lobby (session_id = current ledger sequence cast to u32).
-    pub fn create_lobby(env: Env, player1: Address) -> u32 {
-        player1.require_auth();
+    // Create a lobby staking `points` in a dungeon shaped by `config`. The id
+    // doubles as the hub session id, so it is hashed from (this contract,
+    // creator, nonce) rather than counted from 1.
+    pub fn create_lobby(
+        env: Env,
+        player1: Address,
+        points: i128,
+        config: DungeonConfig,
+    ) -> Result<u32, Error> {
+        // The creator consents to locking this stake in this dungeon
+        player1.require_auth_for_args(vec![&env, points.into_val(&env), config.into_val(&env)]);
+        if points < 0 {
+            return Err(Error::InvalidStake);
+        }
+        validate_config(&config)?;
+
+        let nonce: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LobbyNonce)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::LobbyNonce, &(nonce + 1));
+        let preimage = (env.current_contract_address(), player1.clone(), nonce).to_xdr(&env);
+        let digest = env.crypto().sha256(&preimage).to_array();
+        let lobby_id = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+        if env.storage().temporary().has(&DataKey::Lobby(lobby_id)) {
+            return Err(Error::LobbyExists);
+        }
+
+        let mut open = load_open_lobbies(&env);
+        let listed_by_creator = open.iter().filter(|entry| entry.player1 == player1).count() as u32;
+        if open.len() < MAX_OPEN_LOBBIES && listed_by_creator < MAX_OPEN_LOBBIES_PER_CREATOR {
+            open.push_back(OpenLobby {
+                lobby_id,
+                player1: player1.clone(),
+                stake: points,
+                depth: config.depth,
+                created_ledger: env.ledger().sequence(),
+            });
+            save_open_lobbies(&env, &open);
+        }
 
-        let lobby_id: u32 = (env.ledger().sequence() & 0xFFFF_FFFF) as u32;
         let lobby = Lobby {
             status: Status::Waiting,
             player1: player1.clone(),
@@ -109,32 +718,79 @@ impl TheFarm {
                 commit: None,
                 floor: 0,
                 last_nonce: 0,
-                cleared_gate1: false,
-                cleared_gate5: false,
+                pending_door: None,
+                cleared_gates: 0,
+                last_active: 0,
+                failed_attempts: 0,
+                floor_failures: 0,
+                cooldown_until: 0,
+                inventory: Inventory {
+                    keys: 0,
+                    torches: 0,
+                    traps: 0,
+                },
+                looted_floors: 0,
+                trapped_floors: 0,
+                torch_lit: false,
             },
             p2: PlayerState {
                 commit: None,
                 floor: 0,
                 last_nonce: 0,
-                cleared_gate1: false,
-                cleared_gate5: false,
+                pending_door: None,
+                cleared_gates: 0,
+                last_active: 0,
+                failed_attempts: 0,
+                floor_failures: 0,
+                cooldown_until: 0,
+                inventory: Inventory {
+                    keys: 0,
+                    torches: 0,
+                    traps: 0,
+                },
+                looted_floors: 0,
+                trapped_floors: 0,
+                torch_lit: false,
             },
+            config,
             winner: None,
+            stake: points,
+            created_ledger: env.ledger().sequence(),
         };
-        env.storage().temporary().set(&DataKey::Lobby(lobby_id), &lobby);
-        lobby_id
+        save_lobby(&env, lobby_id, &lobby);
+        LobbyCreated {
+            lobby_id,
+            player1,
+            stake: points,
+        }
+        .publish(&env);
+        Ok(lobby_id)
     }
 
-    pub fn join_lobby(env: Env, lobby_id: u32, player2: Address) -> Result<(), Error> {
-        let mut lobby: Lobby = env
-            .storage()
-            .temporary()
-            .get(&DataKey::Lobby(lobby_id))
-            .ok_or(Error::LobbyNotFound)?;
+    // Join a lobby, matching the creator's stake.
+    pub fn join_lobby(
+        env: Env,
+        lobby_id: u32,
+        player2: Address,
+        points: i128,
+    ) -> Result<(), Error> {
+        let mut lobby = load_lobby(&env, lobby_id)?;
         if lobby.status != Status::Waiting {
             return Err(Error::NotWaiting);
         }
-        player2.require_auth();
+        if waiting_expired(&env, &lobby) {
+            return Err(Error::LobbyExpired);
+        }
+        // The joiner consents to locking this stake in this lobby's dungeon
+        player2.require_auth_for_args(vec![
+            &env,
+            lobby_id.into_val(&env),
+            points.into_val(&env),
+            lobby.config.into_val(&env),
+        ]);
+        if points != lobby.stake {
+            return Err(Error::StakeMismatch);
+        }
         if lobby.player2.is_some() {
             return Err(Error::AlreadyJoined);
         }
@@ -142,9 +798,8 @@ impl TheFarm {
             return Err(Error::AlreadyJoined);
         }
         lobby.player2 = Some(player2);
-        env.storage()
-            .temporary()
-            .set(&DataKey::Lobby(lobby_id), &lobby);
+        save_lobby(&env, lobby_id, &lobby);
+        close_open_lobby(&env, lobby_id);
         Ok(())
     }
 
@@ -155,15 +810,18 @@ impl TheFarm {
         player: Address,
         commit: BytesN<32>,
     ) -> Result<(), Error> {
-        let mut lobby: Lobby = env
-            .storage()
-            .temporary()
-            .get(&DataKey::Lobby(lobby_id))
-            .ok_or(Error::LobbyNotFound)?;
+        let mut lobby = load_lobby(&env, lobby_id)?;
         if lobby.status != Status::Waiting {
             return Err(Error::NotWaiting);
         }
+        if waiting_expired(&env, &lobby) {
+            return Err(Error::LobbyExpired);
+        }
         player.require_auth();
+        // The commit is a public input of every door proof
+        if commit.to_array() >= FR_MODULUS {
+            return Err(Error::InvalidCommit);
+        }
         if player == lobby.player1 {
             if lobby.p1.commit.is_some() {
                 return Err(Error::AlreadyCommitted);
@@ -179,44 +837,43 @@ impl TheFarm {
         }
 
         // If ready, start game and call hub.start_game
-        if lobby.player2.is_some() && lobby.p1.commit.is_some() && lobby.p2.commit.is_some() {
+        if let (Some(player2), true, true) = (
+            lobby.player2.clone(),
+            lobby.p1.commit.is_some(),
+            lobby.p2.commit.is_some(),
+        ) {
             lobby.status = Status::Active;
             lobby.p1.floor = 1;
             lobby.p2.floor = 1;
-            let hub: Address = env.storage().instance().get(&DataKey::Hub).unwrap();
-            let hub_client = GameHubClient::new(&env, &hub);
-            // Points = 0 for now; ZK proof handles correctness, not wagering.
-            hub_client.start_game(
+            // The abandonment clock starts when the dungeon opens
+            lobby.p1.last_active = env.ledger().sequence();
+            lobby.p2.last_active = env.ledger().sequence();
+            hub_client(&env).start_game(
                 &env.current_contract_address(),
                 &lobby_id,
                 &lobby.player1,
-                lobby.player2.as_ref().unwrap(),
-                &0,
-                &0,
+                &player2,
+                &lobby.stake,
+                &lobby.stake,
             );
         }
 
-        env.storage()
-            .temporary()
-            .set(&DataKey::Lobby(lobby_id), &lobby);
+        save_lobby(&env, lobby_id, &lobby);
         Ok(())
     }
 
-    /// Attempt a door. ZK proof verification is stubbed: caller passes `is_correct`
-    /// which will be replaced by verifier output once integrated.
+    /// Attempt `door` on the player's current floor of the opponent's layout.
+    /// The attempt spends the nonce and waits for the opponent's `answer_door`;
+    /// the player cannot attempt again until it is answered.
     pub fn attempt_door(
         env: Env,
         lobby_id: u32,
         player: Address,
         floor: u32,
         attempt_nonce: u32,
-        is_correct: bool,
+        door: u32,
     ) -> Result<(), Error> {
-        let mut lobby: Lobby = env
-            .storage()
-            .temporary()
-            .get(&DataKey::Lobby(lobby_id))
-            .ok_or(Error::LobbyNotFound)?;
+        let lobby = load_lobby(&env, lobby_id)?;
         if lobby.status != Status::Active {
             return Err(Error::LobbyInactive);
         }
@@ -225,78 +882,361 @@ impl TheFarm {
         }
         player.require_auth();
 
-        let caller_is_p1 = player == lobby.player1;
-        let mut p1 = lobby.p1.clone();
-        let mut p2 = lobby.p2.clone();
-        let (mut self_state, mut other_state) = if caller_is_p1 {
-            (p1.clone(), p2.clone())
-        } else if Some(player.clone()) == lobby.player2 {
-            (p2.clone(), p1.clone())
-        } else {
-            return Err(Error::NotPlayer);
-        };
+        let (caller_is_p1, mut self_state, other_state) = player_states(&lobby, &player)?;
 
-        if self_state.commit.is_none() {
+        if other_state.commit.is_none() {
             return Err(Error::MissingCommit);
         }
+        if self_state.pending_door.is_some() {
+            return Err(Error::AttemptPending);
+        }
         if self_state.floor != floor {
             return Err(Error::WrongFloor);
         }
         if attempt_nonce != self_state.last_nonce + 1 {
             return Err(Error::BadNonce);
         }
+        if door >= DOORS_PER_FLOOR {
+            return Err(Error::InvalidDoor);
+        }
+        if env.ledger().sequence() < self_state.cooldown_until {
+            return Err(Error::CoolingDown);
+        }
         self_state.last_nonce = attempt_nonce;
+        self_state.pending_door = Some(door);
+        self_state.last_active = env.ledger().sequence();
 
-        if is_correct {
-            let next_floor = floor + 1;
+        finish_turn(
+            &env,
+            lobby_id,
+            lobby,
+            caller_is_p1,
+            self_state,
+            other_state,
+            None,
+        );
+        Ok(())
+    }
 
-            // Gate floors 1 and 5 require both players.
-            if floor == 1 {
-                self_state.cleared_gate1 = true;
-                if other_state.cleared_gate1 {
-                    self_state.floor = next_floor;
-                    other_state.floor = next_floor;
+    /// Answer the opponent's pending attempt on the caller's layout with a
+    /// Groth16 proof over (lobby_id, floor, nonce, door, opened, commit), where
+    /// `commit` is the caller's. The proof must verify; `opened` says whether
+    /// the door matches the layout. A verified wrong door applies the
+    /// dungeon's penalties to the opponent.
+    pub fn answer_door(
+        env: Env,
+        lobby_id: u32,
+        player: Address,
+        opened: bool,
+        proof: Proof,
+    ) -> Result<(), Error> {
+        let lobby = load_lobby(&env, lobby_id)?;
+        if lobby.status != Status::Active {
+            return Err(Error::LobbyInactive);
+        }
+        if lobby.winner.is_some() {
+            return Err(Error::GameFinished);
+        }
+        player.require_auth();
+
+        let (caller_is_p1, mut self_state, mut other_state) = player_states(&lobby, &player)?;
+
+        let Some(commit) = self_state.commit.clone() else {
+            return Err(Error::MissingCommit);
+        };
+        let Some(door) = other_state.pending_door else {
+            return Err(Error::NoPendingAttempt);
+        };
+        let floor = other_state.floor;
+
+        let vk: VerifyingKey = env
+            .storage()
+            .instance()
+            .get(&DataKey::VerifyingKey)
+            .ok_or(Error::VerifyingKeyNotSet)?;
+        let inputs = door_inputs(
+            &env,
+            lobby_id,
+            floor,
+            other_state.last_nonce,
+            door,
+            opened,
+            &commit,
+        );
+        if !verify_groth16(&env, &vk, &proof, inputs) {
+            return Err(Error::InvalidProof);
+        }
+        other_state.pending_door = None;
+        self_state.last_active = env.ledger().sequence();
+
+        // Some(caller_won) once the answer decides the game
+        let self_floor = self_state.floor;
+        let decided = if opened {
+            let transition = open_door(&lobby.config, &mut other_state, &mut self_state);
+            (transition == Transition::Escape).then_some(false)
+        } else {
+            penalize(&env, &lobby.config, &mut other_state).then_some(true)
+        };
+        settle_move(&lobby.config, &mut other_state, floor);
+        settle_move(&lobby.config, &mut self_state, self_floor);
+
+        finish_turn(
+            &env,
+            lobby_id,
+            lobby,
+            caller_is_p1,
+            self_state,
+            other_state,
+            decided,
+        );
+        Ok(())
+    }
+
+    /// Spend an item from the player's inventory:
+    /// - `Key` opens the door on the player's current floor without a proof;
+    ///   it counts as an attempt, so it waits out a cooldown, cannot be used
+    ///   while an attempt is pending and can be trapped
+    /// - `Torch` makes the player's next verified wrong door penalty-free
+    /// - `Trap` is set on the opponent's next floor and catches their first
+    ///   clear there (one trap per floor)
+    pub fn use_item(env: Env, lobby_id: u32, player: Address, item: Item) -> Result<(), Error> {
+        let lobby = load_lobby(&env, lobby_id)?;
+        if lobby.status != Status::Active {
+            return Err(Error::LobbyInactive);
+        }
+        if lobby.winner.is_some() {
+            return Err(Error::GameFinished);
+        }
+        player.require_auth();
+        let (caller_is_p1, mut self_state, mut other_state) = player_states(&lobby, &player)?;
+
+        let (self_floor, other_floor) = (self_state.floor, other_state.floor);
+        let mut decided = None;
+        match item {
+            Item::Key => {
+                if self_state.inventory.keys == 0 {
+                    return Err(Error::NoItem);
                 }
-            } else if floor == 5 {
-                self_state.cleared_gate5 = true;
-                if other_state.cleared_gate5 {
-                    self_state.floor = next_floor;
-                    other_state.floor = next_floor;
+                if self_state.pending_door.is_some() {
+                    return Err(Error::AttemptPending);
                 }
-            } else {
-                self_state.floor = next_floor;
+                if env.ledger().sequence() < self_state.cooldown_until {
+                    return Err(Error::CoolingDown);
+                }
+                self_state.inventory.keys -= 1;
+                let transition = open_door(&lobby.config, &mut self_state, &mut other_state);
+                decided = (transition == Transition::Escape).then_some(true);
             }
-
-            // Win condition
-            if next_floor > MAX_FLOOR {
-                lobby.status = Status::Finished;
-                lobby.winner = Some(player.clone());
-                let hub: Address = env.storage().instance().get(&DataKey::Hub).unwrap();
-                let hub_client = GameHubClient::new(&env, &hub);
-                let player1_won = player == lobby.player1;
-                hub_client.end_game(&lobby_id, &player1_won);
+            Item::Torch => {
+                if self_state.inventory.torches == 0 {
+                    return Err(Error::NoItem);
+                }
+                if self_state.torch_lit {
+                    return Err(Error::InvalidItemUse);
+                }
+                self_state.inventory.torches -= 1;
+                self_state.torch_lit = true;
             }
+            Item::Trap => {
+                if self_state.inventory.traps == 0 {
+                    return Err(Error::NoItem);
+                }
+                let target = other_state.floor + 1;
+                if target > lobby.config.depth
+                    || other_state.trapped_floors & floor_bit(target) != 0
+                {
+                    return Err(Error::InvalidItemUse);
+                }
+                self_state.inventory.traps -= 1;
+                other_state.trapped_floors |= floor_bit(target);
+            }
+        }
+        self_state.last_active = env.ledger().sequence();
+        settle_move(&lobby.config, &mut self_state, self_floor);
+        settle_move(&lobby.config, &mut other_state, other_floor);
+
+        finish_turn(
+            &env,
+            lobby_id,
+            lobby,
+            caller_is_p1,
+            self_state,
+            other_state,
+            decided,
+        );
+        Ok(())
+    }
+
+    /// Withdraw a Waiting lobby. The creator may cancel while nobody has joined,
+    /// or at any time once the lobby has expired unstarted. No hub session has
+    /// started yet, so there is nothing to settle.
+    pub fn cancel_lobby(env: Env, lobby_id: u32) -> Result<(), Error> {
+        let mut lobby = load_lobby(&env, lobby_id)?;
+        if lobby.status != Status::Waiting {
+            return Err(Error::NotWaiting);
+        }
+        lobby.player1.require_auth();
+        if lobby.player2.is_some() && !waiting_expired(&env, &lobby) {
+            return Err(Error::NotExpired);
+        }
+        lobby.status = Status::Cancelled;
+        save_lobby(&env, lobby_id, &lobby);
+        close_open_lobby(&env, lobby_id);
+        Ok(())
+    }
+
+    /// Settle an Active lobby once nobody has moved for `ABANDON_LEDGERS`. The
+    /// player the game is waiting on loses: the one owing an answer, or at a
+    /// gate the one who has not cleared it. If both could move, the less
+    /// recently active player loses, and if both last acted in the same ledger
+    /// the session ends in a draw. Anyone may call.
+    pub fn settle_abandoned(env: Env, lobby_id: u32) -> Result<(), Error> {
+        let mut lobby = load_lobby(&env, lobby_id)?;
+        if lobby.status != Status::Active {
+            return Err(Error::LobbyInactive);
+        }
+        let last_move = lobby.p1.last_active.max(lobby.p2.last_active);
+        if env.ledger().sequence() <= last_move + ABANDON_LEDGERS {
+            return Err(Error::NotExpired);
         }
 
-        // Write back updated state
-        if caller_is_p1 {
-            p1 = self_state;
-            p2 = other_state;
+        lobby.status = Status::Abandoned;
+        let p1_owes = owes_move(&lobby.config, &lobby.p1, &lobby.p2);
+        let p2_owes = owes_move(&lobby.config, &lobby.p2, &lobby.p1);
+        // Some(player1_won), or None for a draw
+        let outcome = if p1_owes != p2_owes {
+            Some(p2_owes)
+        } else if lobby.p1.last_active != lobby.p2.last_active {
+            Some(lobby.p1.last_active > lobby.p2.last_active)
         } else {
-            p2 = self_state;
-            p1 = other_state;
+            None
+        };
+        let hub = hub_client(&env);
+        if let Some(player1_won) = outcome {
+            lobby.winner = if player1_won {
+                Some(lobby.player1.clone())
+            } else {
+                lobby.player2.clone()
+            };
+            hub.end_game(&lobby_id, &player1_won);
+        } else {
+            hub.end_game_draw(&lobby_id);
         }
-        lobby.p1 = p1;
-        lobby.p2 = p2;
+        save_lobby(&env, lobby_id, &lobby);
+        Ok(())
+    }
 
-        env.storage()
-            .temporary()
-            .set(&DataKey::Lobby(lobby_id), &lobby);
+    /// Concede an Active lobby. The opponent wins through the hub's `end_game`.
+    pub fn resign(env: Env, lobby_id: u32, player: Address) -> Result<(), Error> {
+        let lobby = load_lobby(&env, lobby_id)?;
+        if lobby.status != Status::Active {
+            return Err(Error::LobbyInactive);
+        }
+        if lobby.winner.is_some() {
+            return Err(Error::GameFinished);
+        }
+        player.require_auth();
+        let (caller_is_p1, me, other) = player_states(&lobby, &player)?;
+        finish_turn(&env, lobby_id, lobby, caller_is_p1, me, other, Some(false));
         Ok(())
     }
 
-    pub fn get_lobby(env: Env, lobby_id: u32) -> Option<Lobby> {
-        env.storage().temporary().get(&DataKey::Lobby(lobby_id))
+    /// Cancel an Active lobby by agreement (status `Aborted`). Both players
+    /// must sign; the hub session is cancelled with `cancel_game`, refunding
+    /// both stakes.
+    pub fn abort(env: Env, lobby_id: u32) -> Result<(), Error> {
+        let mut lobby = load_lobby(&env, lobby_id)?;
+        if lobby.status != Status::Active {
+            return Err(Error::LobbyInactive);
+        }
+        lobby.player1.require_auth();
+        if let Some(player2) = &lobby.player2 {
+            player2.require_auth();
+        }
+        lobby.status = Status::Aborted;
+        save_lobby(&env, lobby_id, &lobby);
+        hub_client(&env).cancel_game(&lobby_id);
+        Ok(())
+    }
+
+    /// Public progress of a lobby: floors, gates, status and winner. Commits,
+    /// nonces and inventories are left out; see `get_player_state`.
+    pub fn get_progress(env: Env, lobby_id: u32) -> Option<LobbyProgress> {
+        let lobby: Lobby = env.storage().temporary().get(&DataKey::Lobby(lobby_id))?;
+        Some(LobbyProgress {
+            status: lobby.status,
+            player1: lobby.player1,
+            player2: lobby.player2,
+            p1: progress(&lobby.p1),
+            p2: progress(&lobby.p2),
+            depth: lobby.config.depth,
+            gate_floors: lobby.config.gate_floors,
+            winner: lobby.winner,
+            stake: lobby.stake,
+        })
+    }
+
+    /// A player's full state in a lobby, including their commit and inventory.
+    /// Only served to that player.
+    pub fn get_player_state(
+        env: Env,
+        lobby_id: u32,
+        player: Address,
+    ) -> Result<PlayerState, Error> {
+        let lobby = load_lobby(&env, lobby_id)?;
+        player.require_auth();
+        let (_, state, _) = player_states(&lobby, &player)?;
+        Ok(state)
+    }
+
+    /// Joinable lobbies in creation order. `min_stake` and
+    /// `creator` filter the index; `start` and `limit` page through the
+    /// matches, at most `MAX_LOBBY_PAGE` at a time.
+    pub fn list_open_lobbies(
+        env: Env,
+        min_stake: Option<i128>,
+        creator: Option<Address>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<OpenLobby> {
+        let limit = limit.min(MAX_LOBBY_PAGE);
+        let mut page = Vec::new(&env);
+        let mut matched = 0;
+        for entry in load_open_lobbies(&env).iter() {
+            if page.len() == limit {
+                break;
+            }
+            if min_stake.is_some_and(|min| entry.stake < min)
+                || creator.as_ref().is_some_and(|c| *c != entry.player1)
+            {
+                continue;
+            }
+            if matched >= start {
+                page.push_back(entry);
+            }
+            matched += 1;
+        }
+        page
+    }
+
+    /// The opponent's attempt waiting for `player` to answer it on their layout
+    pub fn get_pending_attempt(
+        env: Env,
+        lobby_id: u32,
+        player: Address,
+    ) -> Result<Option<DoorAttempt>, Error> {
+        let lobby = load_lobby(&env, lobby_id)?;
+        let (_, _, other) = player_states(&lobby, &player)?;
+        Ok(other.pending_door.map(|door| DoorAttempt {
+            floor: other.floor,
+            nonce: other.last_nonce,
+            door,
+        }))
+    }
+
+    /// The lobby's dungeon configuration
+    pub fn get_config(env: Env, lobby_id: u32) -> Result<DungeonConfig, Error> {
+        Ok(load_lobby(&env, lobby_id)?.config)
     }
 
     // Admin utilities
@@ -316,6 +1256,18 @@ impl TheFarm {
         admin.require_auth();
         env.storage().instance().set(&DataKey::Hub, &new_hub);
     }
+    pub fn get_verifying_key(env: Env) -> Option<VerifyingKey> {
+        env.storage().instance().get(&DataKey::VerifyingKey)
+    }
+    pub fn set_verifying_key(env: Env, vk: VerifyingKey) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if vk.ic.len() != PUBLIC_INPUTS + 1 {
+            return Err(Error::InvalidVerifyingKey);
+        }
+        env.storage().instance().set(&DataKey::VerifyingKey, &vk);
+        Ok(())
+    }
     pub fn upgrade(env: Env, new_hash: BytesN<32>) {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -323,4 +1275,6 @@ impl TheFarm {
     }
 }
 
-// Tests are omitted in this scaffold; will be added with Noir verifier integration.
+// ── Tests ────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod test;
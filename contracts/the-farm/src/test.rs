@@ -1,43 +1,220 @@
 #![cfg(test)]
 
-// Unit tests for the the-farm contract using a simple mock GameHub.
-// These tests verify game logic independently of the full GameHub system.
+// Unit tests for the the-farm contract using a mock GameHub that records calls.
+// These tests verify lobby and progression logic independently of the full
+// GameHub system.
 //
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, TheFarmContract, TheFarmContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::{
+    clear_floor, layout_commitment, layout_door, DataKey, DoorAttempt, DungeonConfig, Error,
+    Inventory, Item, ItemGrant, Lobby, LobbyCreated, LobbyProgress, OpenLobby, PlayerProgress,
+    PlayerState, Proof, Status, TheFarm, TheFarmClient, Transition, VerifyingKey, ABANDON_LEDGERS,
+    DOORS_PER_FLOOR, LOBBY_TTL_LEDGERS, MAX_COOLDOWN_LEDGERS, MAX_DEPTH, MAX_LOBBY_PAGE,
+    MAX_OPEN_LOBBIES, MAX_OPEN_LOBBIES_PER_CREATOR, WAITING_EXPIRY_LEDGERS,
+};
+use soroban_sdk::crypto::bls12_381::{Fr, G1Affine, G2Affine};
+use soroban_sdk::testutils::{
+    storage::Temporary as _, Address as _, AuthorizedFunction, Events as _, Ledger as _,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env, Event, IntoVal, Symbol,
+    Vec, U256,
+};
 
 // ============================================================================
-// Mock GameHub for Unit Testing
+// Recording GameHub for Unit Testing
 // ============================================================================
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StartCall {
+    pub game_id: Address,
+    pub session_id: u32,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EndCall {
+    pub session_id: u32,
+    pub player1_won: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum HubKey {
+    Started,
+    Ended,
+    Drawn,
+    Cancelled,
+}
+
 #[contract]
-pub struct MockGameHub;
+pub struct RecordingHub;
 
 #[contractimpl]
-impl MockGameHub {
+impl RecordingHub {
     pub fn start_game(
-        _env: Env,
-        _game_id: Address,
-        _session_id: u32,
-        _player1: Address,
-        _player2: Address,
-        _player1_points: i128,
-        _player2_points: i128,
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
     ) {
-        // Mock implementation - does nothing
+        let mut calls = Self::started(env.clone());
+        calls.push_back(StartCall {
+            game_id,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        });
+        env.storage().instance().set(&HubKey::Started, &calls);
+    }
+
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        let mut calls = Self::ended(env.clone());
+        calls.push_back(EndCall {
+            session_id,
+            player1_won,
+        });
+        env.storage().instance().set(&HubKey::Ended, &calls);
+    }
+
+    pub fn end_game_draw(env: Env, session_id: u32) {
+        let mut calls = Self::drawn(env.clone());
+        calls.push_back(session_id);
+        env.storage().instance().set(&HubKey::Drawn, &calls);
+    }
+
+    pub fn cancel_game(env: Env, session_id: u32) {
+        let mut calls = Self::cancelled(env.clone());
+        calls.push_back(session_id);
+        env.storage().instance().set(&HubKey::Cancelled, &calls);
+    }
+
+    pub fn started(env: Env) -> Vec<StartCall> {
+        env.storage()
+            .instance()
+            .get(&HubKey::Started)
+            .unwrap_or(Vec::new(&env))
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    pub fn ended(env: Env) -> Vec<EndCall> {
+        env.storage()
+            .instance()
+            .get(&HubKey::Ended)
+            .unwrap_or(Vec::new(&env))
     }
 
-    pub fn add_game(_env: Env, _game_address: Address) {
-        // Mock implementation - does nothing
+    pub fn drawn(env: Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&HubKey::Drawn)
+            .unwrap_or(Vec::new(&env))
     }
+
+    pub fn cancelled(env: Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&HubKey::Cancelled)
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+// ============================================================================
+// Trapdoor Groth16 Key
+// ============================================================================
+//
+// The test verifying key is built from known scalars, which lets the tests
+// produce valid proofs for any public inputs without running a prover:
+// alpha = ALPHA·g, beta = BETA·h, gamma = delta = h, ic[i] = IC[i]·g.
+// A proof A = a·g, B = h, C = C_SCALAR·g verifies iff
+// a = ALPHA·BETA + vk_x + C_SCALAR, with vk_x the IC-weighted sum of inputs.
+
+const ALPHA: u32 = 3;
+const BETA: u32 = 5;
+const IC: [u32; 7] = [7, 11, 13, 17, 19, 29, 31];
+const C_SCALAR: u32 = 23;
+const DST: &[u8] = b"THE-FARM-TEST-V01-CS01-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+fn fr(env: &Env, value: u32) -> Fr {
+    Fr::from_u256(U256::from_u32(env, value))
+}
+
+fn g1(env: &Env) -> G1Affine {
+    env.crypto().bls12_381().hash_to_g1(
+        &Bytes::from_slice(env, b"the-farm g1"),
+        &Bytes::from_slice(env, DST),
+    )
+}
+
+fn g2(env: &Env) -> G2Affine {
+    env.crypto().bls12_381().hash_to_g2(
+        &Bytes::from_slice(env, b"the-farm g2"),
+        &Bytes::from_slice(env, DST),
+    )
+}
+
+fn test_verifying_key(env: &Env) -> VerifyingKey {
+    let mut ic = Vec::new(env);
+    for scalar in IC {
+        ic.push_back(g1(env) * fr(env, scalar));
+    }
+    VerifyingKey {
+        alpha: g1(env) * fr(env, ALPHA),
+        beta: g2(env) * fr(env, BETA),
+        gamma: g2(env),
+        delta: g2(env),
+        ic,
+    }
+}
+
+/// A valid proof for the given public inputs under the test verifying key
+fn groth16_proof(env: &Env, inputs: [Fr; 6]) -> Proof {
+    let mut vk_x = fr(env, IC[0]);
+    for (input, scalar) in inputs.into_iter().zip(&IC[1..]) {
+        vk_x = vk_x + input * fr(env, *scalar);
+    }
+    let a = fr(env, ALPHA) * fr(env, BETA) + vk_x + fr(env, C_SCALAR);
+    Proof {
+        a: g1(env) * a,
+        b: g2(env),
+        c: g1(env) * fr(env, C_SCALAR),
+    }
+}
+
+/// Stand-in for the door circuit's prover: like the real circuit, it proves
+/// whether `door` opens on `floor` of the layout behind `seed`'s secret
+fn prove_door(
+    env: &Env,
+    lobby_id: u32,
+    floor: u32,
+    nonce: u32,
+    door: u32,
+    seed: u8,
+) -> (bool, Proof) {
+    let opened = door == layout_door(env, &secret(env, seed), floor);
+    let proof = groth16_proof(
+        env,
+        [
+            fr(env, lobby_id),
+            fr(env, floor),
+            fr(env, nonce),
+            fr(env, door),
+            fr(env, opened as u32),
+            Fr::from_bytes(commitment(env, seed)),
+        ],
+    );
+    (opened, proof)
 }
 
 // ============================================================================
@@ -46,15 +223,15 @@ impl MockGameHub {
 
 fn setup_test() -> (
     Env,
-    TheFarmContractClient<'static>,
-    MockGameHubClient<'static>,
+    TheFarmClient<'static>,
+    RecordingHubClient<'static>,
     Address,
     Address,
 ) {
     let env = Env::default();
     env.mock_all_auths();
 
-    // Set ledger info for time-based operations
+    // Set ledger info
     env.ledger().set(soroban_sdk::testutils::LedgerInfo {
         timestamp: 1441065600,
         protocol_version: 25,
@@ -66,19 +243,15 @@ fn setup_test() -> (
         max_entry_ttl: u32::MAX / 2,
     });
 
-    // Deploy mock GameHub contract
-    let hub_addr = env.register(MockGameHub, ());
-    let game_hub = MockGameHubClient::new(&env, &hub_addr);
-
-    // Create admin address
-    let admin = Address::generate(&env);
+    // Deploy recording GameHub contract
+    let hub_addr = env.register(RecordingHub, ());
+    let game_hub = RecordingHubClient::new(&env, &hub_addr);
 
     // Deploy the-farm with admin and GameHub address
-    let contract_id = env.register(TheFarmContract, (&admin, &hub_addr));
-    let client = TheFarmContractClient::new(&env, &contract_id);
-
-    // Register the-farm as a whitelisted game (mock does nothing)
-    game_hub.add_game(&contract_id);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TheFarm, (&admin, &hub_addr));
+    let client = TheFarmClient::new(&env, &contract_id);
+    client.set_verifying_key(&test_verifying_key(&env));
 
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
@@ -86,27 +259,143 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
-/// Assert that a Result contains a specific number_guess error
-///
-/// This helper provides type-safe error assertions following Stellar/Soroban best practices.
-/// Instead of using `assert_eq!(result, Err(Ok(Error::AlreadyGuessed)))`, this pattern:
-/// - Provides compile-time error checking
-/// - Makes tests more readable with named errors
-/// - Gives better failure messages
-///
-/// # Example
-/// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
-/// assert_number_guess_error(&result, Error::AlreadyGuessed);
-/// ```
-///
-/// # Type Signature
-/// The try_ methods return: `Result<Result<T, T::Error>, Result<E, InvokeError>>`
-/// - Ok(Ok(value)): Call succeeded, decode succeeded
-/// - Ok(Err(conv_err)): Call succeeded, decode failed
-/// - Err(Ok(error)): Contract reverted with custom error (THIS IS WHAT WE TEST)
-/// - Err(Err(invoke_err)): Low-level invocation failure
-fn assert_number_guess_error<T, E>(
+const STAKE: i128 = 100;
+
+/// A dungeon without penalties
+fn dungeon(env: &Env, depth: u32, gate_floors: &[u32]) -> DungeonConfig {
+    DungeonConfig {
+        depth,
+        gate_floors: Vec::from_slice(env, gate_floors),
+        max_failures: 0,
+        knockback: false,
+        cooldown_ledgers: 0,
+        item_grants: Vec::new(env),
+    }
+}
+
+/// Ten floors with cooperative gates on floors 1 and 5
+fn classic_dungeon(env: &Env) -> DungeonConfig {
+    dungeon(env, 10, &[1, 5])
+}
+
+fn secret(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+fn commitment(env: &Env, seed: u8) -> BytesN<32> {
+    layout_commitment(env, &secret(env, seed))
+}
+
+/// Create a lobby, join it and commit for both players, starting the game
+fn start_active_lobby(
+    env: &Env,
+    client: &TheFarmClient,
+    player1: &Address,
+    player2: &Address,
+) -> u32 {
+    let lobby_id = client.create_lobby(player1, &STAKE, &classic_dungeon(env));
+    client.join_lobby(&lobby_id, player2, &STAKE);
+    client.set_commit(&lobby_id, player1, &commitment(env, 1));
+    client.set_commit(&lobby_id, player2, &commitment(env, 2));
+    lobby_id
+}
+
+/// The full stored lobby, which the contract only serves in redacted views
+fn stored_lobby(client: &TheFarmClient, lobby_id: u32) -> Lobby {
+    client.env.as_contract(&client.address, || {
+        client
+            .env
+            .storage()
+            .temporary()
+            .get(&DataKey::Lobby(lobby_id))
+            .unwrap()
+    })
+}
+
+/// Whether the player has cleared the gate on `floor`
+fn cleared(state: &PlayerState, floor: u32) -> bool {
+    state.cleared_gates & (1 << (floor - 1)) != 0
+}
+
+fn player_state(lobby: &Lobby, player: &Address) -> PlayerState {
+    if *player == lobby.player1 {
+        lobby.p1.clone()
+    } else {
+        lobby.p2.clone()
+    }
+}
+
+/// Player 1 commits seed 1, player 2 seed 2
+fn player_seed(lobby: &Lobby, player: &Address) -> u8 {
+    if *player == lobby.player1 {
+        1
+    } else {
+        2
+    }
+}
+
+fn opponent(lobby: &Lobby, player: &Address) -> Address {
+    if *player == lobby.player1 {
+        lobby.player2.clone().unwrap()
+    } else {
+        lobby.player1.clone()
+    }
+}
+
+/// The correct door on the player's current floor of the opponent's layout
+fn correct_door(client: &TheFarmClient, lobby_id: u32, player: &Address) -> u32 {
+    let lobby = stored_lobby(client, lobby_id);
+    let seed = player_seed(&lobby, &opponent(&lobby, player));
+    let floor = player_state(&lobby, player).floor;
+    layout_door(&client.env, &secret(&client.env, seed), floor)
+}
+
+/// Answer the pending attempt on `owner`'s layout with an honest proof
+fn answer(client: &TheFarmClient, lobby_id: u32, owner: &Address) {
+    let lobby = stored_lobby(client, lobby_id);
+    let climber = player_state(&lobby, &opponent(&lobby, owner));
+    let (opened, proof) = prove_door(
+        &client.env,
+        lobby_id,
+        climber.floor,
+        climber.last_nonce,
+        climber.pending_door.unwrap(),
+        player_seed(&lobby, owner),
+    );
+    client.answer_door(&lobby_id, owner, &opened, &proof);
+}
+
+/// Attempt the player's current floor with the next nonce, picking either the
+/// correct door or a wrong one, and have the opponent answer it
+fn attempt(client: &TheFarmClient, lobby_id: u32, player: &Address, is_correct: bool) {
+    let lobby = stored_lobby(client, lobby_id);
+    let state = player_state(&lobby, player);
+    let correct = correct_door(client, lobby_id, player);
+    let door = if is_correct {
+        correct
+    } else {
+        (correct + 1) % DOORS_PER_FLOOR
+    };
+    client.attempt_door(
+        &lobby_id,
+        player,
+        &state.floor,
+        &(state.last_nonce + 1),
+        &door,
+    );
+    answer(client, lobby_id, &opponent(&lobby, player));
+}
+
+/// Clear floors until the player reaches `floor` (gate floors need the other
+/// player to clear them too)
+fn climb_to(client: &TheFarmClient, lobby_id: u32, player: &Address, floor: u32) {
+    while player_state(&stored_lobby(client, lobby_id), player).floor < floor {
+        attempt(client, lobby_id, player, true);
+    }
+}
+
+/// Assert that a Result contains a specific the-farm error
+fn assert_farm_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
     expected_error: Error,
 ) {
@@ -140,400 +429,1328 @@ fn assert_number_guess_error<T, E>(
 }
 
 // ============================================================================
-// Basic Game Flow Tests
+// Lobby Tests
 // ============================================================================
 
 #[test]
-fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_create_and_join_lobby() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Waiting);
+    assert_eq!(lobby.player1, player1);
+    assert!(lobby.player2.is_none());
+    assert!(lobby.winner.is_none());
+
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.player2, Some(player2));
+    assert_eq!(lobby.status, Status::Waiting);
+
+    // The hub session only starts once both players have committed
+    assert!(hub.started().is_empty());
+}
 
-    let session_id = 1u32;
-    let points = 100_0000000;
+#[test]
+fn test_lobby_ids_are_unique_within_a_ledger() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    // Several lobbies in the same ledger, including two by the same creator
+    let first = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [LobbyCreated {
+            lobby_id: first,
+            player1: player1.clone(),
+            stake: STAKE,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    let second = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    let third = client.create_lobby(&player2, &STAKE, &classic_dungeon(&env));
+    assert_ne!(first, second);
+    assert_ne!(first, third);
+    assert_ne!(second, third);
+
+    // Each lobby keeps its own creator
+    assert_eq!(stored_lobby(&client, first).player1, player1);
+    assert_eq!(stored_lobby(&client, second).player1, player1);
+    assert_eq!(stored_lobby(&client, third).player1, player2);
+}
 
-    // Get game to verify state
-    let game = client.get_game(&session_id);
-    assert!(game.winning_number.is_none()); // Winning number not set yet
-    assert!(game.winner.is_none()); // Game is still active
-    assert_eq!(game.player1, player1);
-    assert_eq!(game.player2, player2);
-    assert_eq!(game.player1_points, points);
-    assert_eq!(game.player2_points, points);
+#[test]
+fn test_join_lobby_errors() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    let result = client.try_join_lobby(&999, &player2, &STAKE);
+    assert_farm_error(&result, Error::LobbyNotFound);
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    let result = client.try_join_lobby(&lobby_id, &player1, &STAKE);
+    assert_farm_error(&result, Error::AlreadyJoined);
 
-    // Verify game is ended and winning number is now set
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
-    assert!(final_game.winning_number.is_some());
-    let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    let late = Address::generate(&env);
+    let result = client.try_join_lobby(&lobby_id, &late, &STAKE);
+    assert_farm_error(&result, Error::AlreadyJoined);
 }
 
 #[test]
-fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_stakes_are_matched_and_passed_to_hub() {
+    let (env, client, hub, player1, player2) = setup_test();
 
-    let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let result = client.try_create_lobby(&player1, &-1, &classic_dungeon(&env));
+    assert_farm_error(&result, Error::InvalidStake);
 
-    // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-    client.reveal_winner(&session_id);
+    let lobby_id = client.create_lobby(&player1, &250, &classic_dungeon(&env));
+    assert_eq!(stored_lobby(&client, lobby_id).stake, 250);
 
-    let game = client.get_game(&session_id);
-    let winning_number = game
-        .winning_number
-        .expect("Winning number should be set after reveal");
-    assert!(
-        winning_number >= 1 && winning_number <= 10,
-        "Winning number should be between 1 and 10"
-    );
+    // The joiner must match the creator's stake exactly
+    let result = client.try_join_lobby(&lobby_id, &player2, &249);
+    assert_farm_error(&result, Error::StakeMismatch);
+    client.join_lobby(&lobby_id, &player2, &250);
+
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+    let call = hub.started().get(0).unwrap();
+    assert_eq!(call.player1_points, 250);
+    assert_eq!(call.player2_points, 250);
 }
 
 #[test]
-fn test_multiple_sessions() {
+fn test_commit_gating() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+
+    // Player 1 may commit before anyone joins; outsiders may not commit
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    let result = client.try_set_commit(&lobby_id, &player1, &commitment(&env, 9));
+    assert_farm_error(&result, Error::AlreadyCommitted);
+    let result = client.try_set_commit(&lobby_id, &player2, &commitment(&env, 2));
+    assert_farm_error(&result, Error::NotPlayer);
+
+    // Doors cannot be attempted while waiting
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &1, &0);
+    assert_farm_error(&result, Error::LobbyInactive);
+
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    assert!(hub.started().is_empty());
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Active);
+    assert_eq!(lobby.p1.commit, Some(commitment(&env, 1)));
+    assert_eq!(lobby.p2.commit, Some(commitment(&env, 2)));
+    assert_eq!(lobby.p1.floor, 1);
+    assert_eq!(lobby.p2.floor, 1);
+
+    // The hub session starts exactly once, for this lobby
+    let started = hub.started();
+    assert_eq!(started.len(), 1);
+    let call = started.get(0).unwrap();
+    assert_eq!(call.game_id, client.address);
+    assert_eq!(call.session_id, lobby_id);
+    assert_eq!(call.player1, player1);
+    assert_eq!(call.player2, player2);
+
+    // The lobby is no longer waiting
+    let result = client.try_set_commit(&lobby_id, &player2, &commitment(&env, 3));
+    assert_farm_error(&result, Error::NotWaiting);
+    let late = Address::generate(&env);
+    let result = client.try_join_lobby(&lobby_id, &late, &STAKE);
+    assert_farm_error(&result, Error::NotWaiting);
+}
+
+// ============================================================================
+// Floor Progression Tests
+// ============================================================================
+
+#[test]
+fn test_gate_floor_1_waits_for_both_players() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    // Player 1 clears the gate but stays on floor 1 until player 2 does
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert!(cleared(&lobby.p1, 1));
+    assert_eq!(lobby.p1.floor, 1);
+    assert_eq!(lobby.p2.floor, 1);
+
+    attempt(&client, lobby_id, &player2, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert!(cleared(&lobby.p2, 1));
+    assert_eq!(lobby.p1.floor, 2);
+    assert_eq!(lobby.p2.floor, 2);
+}
 
-    let session1 = 3u32;
-    let session2 = 4u32;
+#[test]
+fn test_non_gate_floors_are_independent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player2, true);
 
-    // Verify both games exist and are independent
-    let game1 = client.get_game(&session1);
-    let game2 = client.get_game(&session2);
+    // Player 1 climbs floors 2-4 alone; a wrong door changes nothing
+    attempt(&client, lobby_id, &player1, false);
+    climb_to(&client, lobby_id, &player1, 5);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 5);
+    assert_eq!(lobby.p2.floor, 2);
+}
 
-    assert_eq!(game1.player1, player1);
-    assert_eq!(game2.player1, player3);
+#[test]
+fn test_gate_floor_5_waits_for_both_players() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player2, true);
+    climb_to(&client, lobby_id, &player1, 5);
+
+    // Player 1 clears gate 5 early and keeps waiting on floor 5
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert!(cleared(&lobby.p1, 5));
+    assert_eq!(lobby.p1.floor, 5);
+
+    // Player 2 catches up; clearing gate 5 moves both players on
+    climb_to(&client, lobby_id, &player2, 5);
+    attempt(&client, lobby_id, &player2, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 6);
+    assert_eq!(lobby.p2.floor, 6);
 }
 
-// ============================================================================
-// Guess Logic Tests
-// ============================================================================
+#[test]
+fn test_attempt_must_match_current_floor() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    let result = client.try_attempt_door(&lobby_id, &player1, &2, &1, &0);
+    assert_farm_error(&result, Error::WrongFloor);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_attempt_door(&lobby_id, &outsider, &1, &1, &0);
+    assert_farm_error(&result, Error::NotPlayer);
+
+    let result = client.try_attempt_door(&999, &player1, &1, &1, &0);
+    assert_farm_error(&result, Error::LobbyNotFound);
+
+    // Doors are numbered 0..DOORS_PER_FLOOR
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &1, &DOORS_PER_FLOOR);
+    assert_farm_error(&result, Error::InvalidDoor);
+}
 
 #[test]
-fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_nonce_replay_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
 
-    let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    // A failed attempt still consumes its nonce
+    attempt(&client, lobby_id, &player1, false);
+    let door = correct_door(&client, lobby_id, &player1);
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &1, &door);
+    assert_farm_error(&result, Error::BadNonce);
 
-    // Player1 guesses closer (1 away from any number between 1-10)
-    // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    // Nonces cannot skip ahead either
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &3, &door);
+    assert_farm_error(&result, Error::BadNonce);
 
-    let winner = client.reveal_winner(&session_id);
+    client.attempt_door(&lobby_id, &player1, &1, &2, &door);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.last_nonce, 2);
 
-    // Get the final game state to check the winning number
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+    // One attempt at a time: the next waits for the answer
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &3, &door);
+    assert_farm_error(&result, Error::AttemptPending);
+    answer(&client, lobby_id, &player2);
 
-    // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    // Each player has their own nonce sequence
+    attempt(&client, lobby_id, &player2, true);
+    assert_eq!(stored_lobby(&client, lobby_id).p2.last_nonce, 1);
+}
 
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
+// ============================================================================
+// Proof Verification Tests
+// ============================================================================
+
+#[test]
+fn test_layout_is_answered_by_its_owner() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    let door = correct_door(&client, lobby_id, &player1);
+    assert_eq!(door, layout_door(&env, &secret(&env, 2), 1));
+    client.attempt_door(&lobby_id, &player1, &1, &1, &door);
     assert_eq!(
-        winner, expected_winner,
-        "Player with closer guess should win"
+        client.get_pending_attempt(&lobby_id, &player2),
+        Some(DoorAttempt {
+            floor: 1,
+            nonce: 1,
+            door,
+        })
     );
+    assert_eq!(client.get_pending_attempt(&lobby_id, &player1), None);
+
+    // The climber cannot answer their own attempt, and a proof over their own
+    // layout does not answer it either
+    let (opened, proof) = prove_door(&env, lobby_id, 1, 1, door, 1);
+    let result = client.try_answer_door(&lobby_id, &player1, &opened, &proof);
+    assert_farm_error(&result, Error::NoPendingAttempt);
+    let result = client.try_answer_door(&lobby_id, &player2, &opened, &proof);
+    assert_farm_error(&result, Error::InvalidProof);
+    let outsider = Address::generate(&env);
+    let result = client.try_answer_door(&lobby_id, &outsider, &opened, &proof);
+    assert_farm_error(&result, Error::NotPlayer);
+
+    // The owner's answer resolves it
+    answer(&client, lobby_id, &player2);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert!(cleared(&lobby.p1, 1));
+    assert_eq!(lobby.p1.pending_door, None);
+    assert_eq!(client.get_pending_attempt(&lobby_id, &player2), None);
+    let result = client.try_answer_door(&lobby_id, &player2, &opened, &proof);
+    assert_farm_error(&result, Error::NoPendingAttempt);
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+fn test_proof_is_bound_to_public_inputs() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+    let door = correct_door(&client, lobby_id, &player1);
+    client.attempt_door(&lobby_id, &player1, &1, &1, &door);
+
+    // Proofs for another lobby, nonce, door or layout are rejected outright
+    // and leave the attempt pending
+    let wrong_proofs = [
+        prove_door(&env, lobby_id + 1, 1, 1, door, 2),
+        prove_door(&env, lobby_id, 1, 2, door, 2),
+        prove_door(&env, lobby_id, 1, 1, (door + 1) % DOORS_PER_FLOOR, 2),
+        prove_door(&env, lobby_id, 1, 1, door, 3),
+    ];
+    for (_, proof) in wrong_proofs {
+        let result = client.try_answer_door(&lobby_id, &player2, &true, &proof);
+        assert_farm_error(&result, Error::InvalidProof);
+    }
+    assert_eq!(stored_lobby(&client, lobby_id).p1.pending_door, Some(door));
 
-    // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    let (_, proof) = prove_door(&env, lobby_id, 1, 1, door, 2);
+    client.answer_door(&lobby_id, &player2, &true, &proof);
+    assert!(cleared(&stored_lobby(&client, lobby_id).p1, 1));
+}
 
-    let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+#[test]
+fn test_door_outcome_is_verified() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    // The owner cannot pass an open door off as a failure
+    let door = correct_door(&client, lobby_id, &player1);
+    client.attempt_door(&lobby_id, &player1, &1, &1, &door);
+    let (opened, proof) = prove_door(&env, lobby_id, 1, 1, door, 2);
+    assert!(opened);
+    let result = client.try_answer_door(&lobby_id, &player2, &false, &proof);
+    assert_farm_error(&result, Error::InvalidProof);
+    client.answer_door(&lobby_id, &player2, &true, &proof);
+    assert!(cleared(&stored_lobby(&client, lobby_id).p1, 1));
+
+    // Nor can a wrong door be claimed open
+    let wrong_door = (correct_door(&client, lobby_id, &player2) + 1) % DOORS_PER_FLOOR;
+    client.attempt_door(&lobby_id, &player2, &1, &1, &wrong_door);
+    let (opened, shut_proof) = prove_door(&env, lobby_id, 1, 1, wrong_door, 1);
+    assert!(!opened);
+    let result = client.try_answer_door(&lobby_id, &player1, &true, &shut_proof);
+    assert_farm_error(&result, Error::InvalidProof);
+
+    // A verified wrong door is recorded as the climber's failure
+    client.answer_door(&lobby_id, &player1, &false, &shut_proof);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert!(!cleared(&lobby.p2, 1));
+    assert_eq!(lobby.p2.failed_attempts, 1);
+    assert_eq!(lobby.p2.last_nonce, 1);
+    assert_eq!(lobby.p2.pending_door, None);
+    assert_eq!(lobby.p1.failed_attempts, 0);
+
+    attempt(&client, lobby_id, &player2, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 2);
+    assert_eq!(lobby.p2.floor, 2);
 }
 
 #[test]
-fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_layout_derivation() {
+    let env = Env::default();
+
+    // Commitments are canonical scalars and bind the secret
+    let commit = commitment(&env, 1);
+    assert_eq!(commit.to_array()[0], 0);
+    assert_eq!(commit, commitment(&env, 1));
+    assert_ne!(commit, commitment(&env, 2));
+
+    // Every floor has a door in range, and the two layouts differ somewhere
+    let mut differs = false;
+    for floor in 1..=MAX_DEPTH {
+        let door1 = layout_door(&env, &secret(&env, 1), floor);
+        let door2 = layout_door(&env, &secret(&env, 2), floor);
+        assert!(door1 < DOORS_PER_FLOOR);
+        assert!(door2 < DOORS_PER_FLOOR);
+        differs |= door1 != door2;
+    }
+    assert!(differs);
+}
 
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+#[test]
+fn test_attempt_requires_verifying_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let hub_addr = env.register(RecordingHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TheFarm, (&admin, &hub_addr));
+    let client = TheFarmClient::new(&env, &contract_id);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
 
-    // Player1 guesses 5 (middle), player2 guesses 10 (edge)
-    // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    assert!(client.get_verifying_key().is_none());
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+    client.attempt_door(&lobby_id, &player1, &1, &1, &0);
+    let (opened, proof) = prove_door(&env, lobby_id, 1, 1, 0, 2);
+    let result = client.try_answer_door(&lobby_id, &player2, &opened, &proof);
+    assert_farm_error(&result, Error::VerifyingKeyNotSet);
+}
 
-    let winner = client.reveal_winner(&session_id);
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+#[test]
+fn test_verifying_key_and_commit_validation() {
+    let (env, client, _hub, player1, _player2) = setup_test();
 
-    // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(winner, expected_winner);
+    // The key must have one IC point per public input plus the constant
+    let mut vk = test_verifying_key(&env);
+    vk.ic.pop_back();
+    let result = client.try_set_verifying_key(&vk);
+    assert_farm_error(&result, Error::InvalidVerifyingKey);
+    assert_eq!(client.get_verifying_key().unwrap().ic.len(), 7);
+
+    // Commits must be canonical scalar field elements
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    let commit = BytesN::from_array(&env, &[0xff; 32]);
+    let result = client.try_set_commit(&lobby_id, &player1, &commit);
+    assert_farm_error(&result, Error::InvalidCommit);
 }
 
 // ============================================================================
-// Error Handling Tests
+// Dungeon Config Tests
 // ============================================================================
 
 #[test]
-fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_invalid_dungeon_configs_rejected() {
+    let (env, client, _hub, player1, _player2) = setup_test();
 
-    let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let invalid = [
+        dungeon(&env, 0, &[]),
+        dungeon(&env, MAX_DEPTH + 1, &[]),
+        // Gates beyond the last floor, repeated or out of order
+        dungeon(&env, 4, &[5]),
+        dungeon(&env, 4, &[2, 2]),
+        dungeon(&env, 4, &[3, 1]),
+        dungeon(&env, 4, &[0]),
+        // The final floor decides the race and cannot be a gate
+        dungeon(&env, 4, &[4]),
+        // Items lie on floors below the last one
+        with_items(dungeon(&env, 4, &[]), &[(0, Item::Key)]),
+        with_items(dungeon(&env, 4, &[]), &[(4, Item::Key)]),
+        // Cooldowns are bounded
+        DungeonConfig {
+            cooldown_ledgers: MAX_COOLDOWN_LEDGERS + 1,
+            ..dungeon(&env, 4, &[])
+        },
+        DungeonConfig {
+            cooldown_ledgers: u32::MAX,
+            ..dungeon(&env, 4, &[])
+        },
+    ];
+    for config in invalid {
+        let result = client.try_create_lobby(&player1, &STAKE, &config);
+        assert_farm_error(&result, Error::InvalidConfig);
+    }
 
-    // Make first guess
-    client.make_guess(&session_id, &player1, &5);
+    let deepest = dungeon(&env, MAX_DEPTH, &[1, MAX_DEPTH - 1]);
+    let lobby_id = client.create_lobby(&player1, &STAKE, &deepest);
+    assert_eq!(stored_lobby(&client, lobby_id).config, deepest);
+}
 
-    // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
-    assert_number_guess_error(&result, Error::AlreadyGuessed);
+#[test]
+fn test_short_dungeon_with_custom_gates() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    // Three floors, a single gate on floor 2
+    let config = dungeon(&env, 3, &[2]);
+    let lobby_id = client.create_lobby(&player1, &STAKE, &config);
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+
+    // Floor 1 is no longer a gate
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 2);
+    assert_eq!(lobby.p2.floor, 1);
+    assert_eq!(lobby.p1.cleared_gates, 0);
+
+    // Floor 2 waits for player 2
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert!(cleared(&lobby.p1, 2));
+    assert_eq!(lobby.p1.floor, 2);
+    climb_to(&client, lobby_id, &player2, 2);
+    attempt(&client, lobby_id, &player2, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 3);
+    assert_eq!(lobby.p2.floor, 3);
+
+    // Clearing floor 3 wins
+    attempt(&client, lobby_id, &player2, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Finished);
+    assert_eq!(lobby.winner, Some(player2));
+    assert!(!hub.ended().get(0).unwrap().player1_won);
+}
+
+// ============================================================================
+// Progression State Machine Tests
+// ============================================================================
+
+fn on_floor(floor: u32, cleared_gates: u32) -> PlayerState {
+    PlayerState {
+        commit: None,
+        floor,
+        last_nonce: 0,
+        pending_door: None,
+        cleared_gates,
+        last_active: 0,
+        failed_attempts: 0,
+        floor_failures: 0,
+        cooldown_until: 0,
+        inventory: Inventory {
+            keys: 0,
+            torches: 0,
+            traps: 0,
+        },
+        looted_floors: 0,
+        trapped_floors: 0,
+        torch_lit: false,
+    }
 }
 
 #[test]
-fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_clear_floor_transitions() {
+    let env = Env::default();
+    let config = dungeon(&env, 4, &[2]);
+
+    // Ordinary floor: only the clearing player moves
+    let (mut me, mut other) = (on_floor(1, 0), on_floor(1, 0));
+    assert_eq!(
+        clear_floor(&config, &mut me, &mut other),
+        Transition::Advance
+    );
+    assert_eq!((me.floor, other.floor), (2, 1));
+
+    // Gate floor first clear: wait, remembering the clear
+    let (mut me, mut other) = (on_floor(2, 0), on_floor(1, 0));
+    assert_eq!(
+        clear_floor(&config, &mut me, &mut other),
+        Transition::WaitAtGate
+    );
+    assert_eq!((me.floor, other.floor), (2, 1));
+    assert!(cleared(&me, 2));
+
+    // Clearing it again while waiting changes nothing
+    assert_eq!(
+        clear_floor(&config, &mut me, &mut other),
+        Transition::WaitAtGate
+    );
+    assert_eq!((me.floor, me.cleared_gates), (2, 1 << 1));
+
+    // Gate floor second clear: both players move up together
+    let (mut me, mut other) = (on_floor(2, 0), on_floor(2, 1 << 1));
+    assert_eq!(
+        clear_floor(&config, &mut me, &mut other),
+        Transition::OpenGate
+    );
+    assert_eq!((me.floor, other.floor), (3, 3));
+
+    // Only the final floor escapes
+    let (mut me, mut other) = (on_floor(3, 1 << 1), on_floor(3, 1 << 1));
+    assert_eq!(
+        clear_floor(&config, &mut me, &mut other),
+        Transition::Advance
+    );
+    assert_eq!(
+        clear_floor(&config, &mut me, &mut other),
+        Transition::Escape
+    );
+    assert_eq!((me.floor, other.floor), (5, 3));
+}
 
-    let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+#[test]
+fn test_gate_below_final_floor_does_not_win() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    // Four floors with a gate just below the top
+    let config = dungeon(&env, 4, &[3]);
+    let lobby_id = client.create_lobby(&player1, &STAKE, &config);
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+
+    // Player 1 races ahead and clears the gate, but cannot go further alone
+    climb_to(&client, lobby_id, &player1, 3);
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Active);
+    assert_eq!(lobby.p1.floor, 3);
+    assert!(hub.ended().is_empty());
+
+    // Player 2 opens the gate; player 1 then escapes from the final floor
+    climb_to(&client, lobby_id, &player2, 3);
+    attempt(&client, lobby_id, &player2, true);
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Finished);
+    assert_eq!(lobby.winner, Some(player1));
+    assert_eq!(lobby.p1.floor, 5);
+    assert_eq!(lobby.p2.floor, 4);
+    assert!(hub.ended().get(0).unwrap().player1_won);
+}
 
-    // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+// ============================================================================
+// Penalty Tests
+// ============================================================================
 
-    // Try to reveal winner - should fail
-    let result = client.try_reveal_winner(&session_id);
-    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+/// Start a lobby in the given dungeon with both players committed
+fn start_dungeon(
+    env: &Env,
+    client: &TheFarmClient,
+    player1: &Address,
+    player2: &Address,
+    config: &DungeonConfig,
+) -> u32 {
+    let lobby_id = client.create_lobby(player1, &STAKE, config);
+    client.join_lobby(&lobby_id, player2, &STAKE);
+    client.set_commit(&lobby_id, player1, &commitment(env, 1));
+    client.set_commit(&lobby_id, player2, &commitment(env, 2));
+    lobby_id
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_max_failures_per_floor_forfeits() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let mut config = dungeon(&env, 4, &[]);
+    config.max_failures = 3;
+    let lobby_id = start_dungeon(&env, &client, &player1, &player2, &config);
+
+    // Failures on one floor do not carry over to the next
+    attempt(&client, lobby_id, &player1, false);
+    attempt(&client, lobby_id, &player1, false);
+    attempt(&client, lobby_id, &player1, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 2);
+    assert_eq!(lobby.p1.floor_failures, 0);
+    assert_eq!(lobby.p1.failed_attempts, 2);
+
+    attempt(&client, lobby_id, &player1, false);
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(stored_lobby(&client, lobby_id).status, Status::Active);
+
+    // The third failure on floor 2 hands the game to player 2
+    attempt(&client, lobby_id, &player1, false);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Finished);
+    assert_eq!(lobby.winner, Some(player2));
+    assert_eq!(lobby.p1.failed_attempts, 5);
+    assert!(!hub.ended().get(0).unwrap().player1_won);
+}
 
-    let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+#[test]
+fn test_knockback_stops_at_gates() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let mut config = dungeon(&env, 6, &[3]);
+    config.knockback = true;
+    let lobby_id = start_dungeon(&env, &client, &player1, &player2, &config);
+
+    // Floor 1 is the bottom
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 1);
+
+    // A failure on floor 2 knocks the player back to floor 1
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 1);
+
+    // Nobody is knocked off the gate floor or back onto it once through
+    climb_to(&client, lobby_id, &player1, 3);
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 3);
+    attempt(&client, lobby_id, &player1, true);
+    climb_to(&client, lobby_id, &player2, 3);
+    attempt(&client, lobby_id, &player2, true);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 4);
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 4);
+
+    // Above that, knockback applies again
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player1, false);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.floor, 4);
+    assert_eq!(lobby.p1.failed_attempts, 5);
+}
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+#[test]
+fn test_cooldown_after_failure() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let mut config = dungeon(&env, 4, &[]);
+    config.cooldown_ledgers = 10;
+    let lobby_id = start_dungeon(&env, &client, &player1, &player2, &config);
+
+    attempt(&client, lobby_id, &player1, false);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p1.cooldown_until, env.ledger().sequence() + 10);
+
+    // Player 1 must wait; player 2 is unaffected
+    let door = correct_door(&client, lobby_id, &player1);
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &2, &door);
+    assert_farm_error(&result, Error::CoolingDown);
+    attempt(&client, lobby_id, &player2, true);
+
+    advance_ledgers(&env, 9);
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &2, &door);
+    assert_farm_error(&result, Error::CoolingDown);
+    advance_ledgers(&env, 1);
+    client.attempt_door(&lobby_id, &player1, &1, &2, &door);
+    answer(&client, lobby_id, &player2);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 2);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_joiner_signs_the_dungeon_penalties() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let mut config = dungeon(&env, 4, &[]);
+    config.cooldown_ledgers = MAX_COOLDOWN_LEDGERS;
+    let lobby_id = client.create_lobby(&player1, &STAKE, &config);
+
+    // The config, cooldown included, is part of what the joiner authorizes
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    let auths = env.auths();
+    assert_eq!(auths[0].0, player2);
+    assert_eq!(
+        auths[0].1.function,
+        AuthorizedFunction::Contract((
+            client.address.clone(),
+            Symbol::new(&env, "join_lobby"),
+            vec![
+                &env,
+                lobby_id.into_val(&env),
+                STAKE.into_val(&env),
+                config.into_val(&env),
+            ],
+        ))
+    );
 
-    let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
+    // The longest cooldown still lets the opponent answer and play on
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+    attempt(&client, lobby_id, &player1, false);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(
+        lobby.p1.cooldown_until,
+        env.ledger().sequence() + MAX_COOLDOWN_LEDGERS
     );
+    assert_eq!(lobby.status, Status::Active);
+}
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+// ============================================================================
+// Item Tests
+// ============================================================================
+
+/// Place items on floors of a dungeon
+fn with_items(mut config: DungeonConfig, items: &[(u32, Item)]) -> DungeonConfig {
+    for (floor, item) in items {
+        config.item_grants.push_back(ItemGrant {
+            floor: *floor,
+            item: *item,
+        });
+    }
+    config
+}
+
+fn inventory(client: &TheFarmClient, lobby_id: u32, player: &Address) -> Inventory {
+    player_state(&stored_lobby(client, lobby_id), player).inventory
 }
 
 #[test]
-fn test_non_player_cannot_guess() {
+fn test_items_are_picked_up_once_per_floor() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let non_player = Address::generate(&env);
+    let mut config = with_items(
+        dungeon(&env, 5, &[]),
+        &[(1, Item::Key), (2, Item::Torch), (2, Item::Trap)],
+    );
+    config.knockback = true;
+    let lobby_id = start_dungeon(&env, &client, &player1, &player2, &config);
+
+    climb_to(&client, lobby_id, &player1, 3);
+    let expected = Inventory {
+        keys: 1,
+        torches: 1,
+        traps: 1,
+    };
+    assert_eq!(inventory(&client, lobby_id, &player1), expected);
+    assert_eq!(
+        inventory(&client, lobby_id, &player2),
+        Inventory {
+            keys: 0,
+            torches: 0,
+            traps: 0,
+        }
+    );
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    // Knocked back and clearing floor 2 again finds nothing new
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(stored_lobby(&client, lobby_id).p1.floor, 2);
+    attempt(&client, lobby_id, &player1, true);
+    assert_eq!(inventory(&client, lobby_id, &player1), expected);
 
-    // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
-    assert_number_guess_error(&result, Error::NotPlayer);
+    // Each player loots their own copy
+    climb_to(&client, lobby_id, &player2, 2);
+    assert_eq!(inventory(&client, lobby_id, &player2).keys, 1);
 }
 
 #[test]
-fn test_cannot_reveal_nonexistent_game() {
-    let (_env, client, _hub, _player1, _player2) = setup_test();
+fn test_key_opens_door_and_torch_absorbs_failure() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let mut config = with_items(
+        dungeon(&env, 3, &[]),
+        &[(1, Item::Torch), (1, Item::Torch), (2, Item::Key)],
+    );
+    config.max_failures = 1;
+    config.cooldown_ledgers = 10;
+    let lobby_id = start_dungeon(&env, &client, &player1, &player2, &config);
+
+    let result = client.try_use_item(&lobby_id, &player1, &Item::Key);
+    assert_farm_error(&result, Error::NoItem);
+
+    // A lit torch turns a failure that would forfeit into a free look
+    attempt(&client, lobby_id, &player1, true);
+    client.use_item(&lobby_id, &player1, &Item::Torch);
+    let result = client.try_use_item(&lobby_id, &player1, &Item::Torch);
+    assert_farm_error(&result, Error::InvalidItemUse);
+    attempt(&client, lobby_id, &player1, false);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Active);
+    assert_eq!(lobby.p1.failed_attempts, 1);
+    assert_eq!(lobby.p1.floor_failures, 0);
+    assert_eq!(lobby.p1.cooldown_until, 0);
+    assert!(!lobby.p1.torch_lit);
+    assert_eq!(lobby.p1.inventory.torches, 1);
+
+    // The key from floor 2 opens the final door without a proof
+    attempt(&client, lobby_id, &player1, true);
+    assert_eq!(inventory(&client, lobby_id, &player1).keys, 1);
+    client.use_item(&lobby_id, &player1, &Item::Key);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Finished);
+    assert_eq!(lobby.winner, Some(player1));
+    assert_eq!(lobby.p1.inventory.keys, 0);
+    assert!(hub.ended().get(0).unwrap().player1_won);
+}
 
-    let result = client.try_reveal_winner(&999);
-    assert_number_guess_error(&result, Error::GameNotFound);
+#[test]
+fn test_trap_catches_opponents_next_clear() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = with_items(dungeon(&env, 3, &[]), &[(1, Item::Trap), (1, Item::Trap)]);
+    let lobby_id = start_dungeon(&env, &client, &player1, &player2, &config);
+
+    // Player 1 traps floor 2, the floor above player 2; one trap per floor
+    attempt(&client, lobby_id, &player1, true);
+    client.use_item(&lobby_id, &player1, &Item::Trap);
+    let result = client.try_use_item(&lobby_id, &player1, &Item::Trap);
+    assert_farm_error(&result, Error::InvalidItemUse);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p2.trapped_floors, 1 << 1);
+    assert_eq!(lobby.p1.inventory.traps, 1);
+
+    // The trap springs on player 2's first clear of floor 2
+    attempt(&client, lobby_id, &player2, true);
+    attempt(&client, lobby_id, &player2, true);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p2.floor, 2);
+    assert_eq!(lobby.p2.trapped_floors, 0);
+    attempt(&client, lobby_id, &player2, true);
+    assert_eq!(stored_lobby(&client, lobby_id).p2.floor, 3);
+
+    // There is no floor above the final one to trap
+    let result = client.try_use_item(&lobby_id, &player1, &Item::Trap);
+    assert_farm_error(&result, Error::InvalidItemUse);
 }
 
+// ============================================================================
+// Win Condition Tests
+// ============================================================================
+
 #[test]
-fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_first_player_past_floor_10_wins() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player2, true);
+    climb_to(&client, lobby_id, &player1, 5);
+    climb_to(&client, lobby_id, &player2, 5);
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player2, true);
+
+    // Player 2 reaches floor 10 first and clears it
+    climb_to(&client, lobby_id, &player2, 10);
+    assert!(hub.ended().is_empty());
+    attempt(&client, lobby_id, &player2, true);
+
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Finished);
+    assert_eq!(lobby.winner, Some(player2.clone()));
+
+    let ended = hub.ended();
+    assert_eq!(ended.len(), 1);
+    let call = ended.get(0).unwrap();
+    assert_eq!(call.session_id, lobby_id);
+    assert!(!call.player1_won);
+
+    // No more attempts once the game is finished
+    let state = player_state(&lobby, &player1);
+    let result = client.try_attempt_door(
+        &lobby_id,
+        &player1,
+        &state.floor,
+        &(state.last_nonce + 1),
+        &0,
+    );
+    assert_farm_error(&result, Error::LobbyInactive);
+}
 
-    let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+// ============================================================================
+// Expiry Tests
+// ============================================================================
 
-    // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+fn lobby_ttl(env: &Env, client: &TheFarmClient, lobby_id: u32) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage().temporary().get_ttl(&DataKey::Lobby(lobby_id))
+    })
+}
 
-    // Reveal winner - game ends
-    let _winner = client.reveal_winner(&session_id);
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ledgers);
+}
 
-    // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
-    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+#[test]
+fn test_lobby_ttl_extended_on_every_update() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    env.ledger().with_mut(|li| {
+        li.min_temp_entry_ttl = 16;
+        li.max_entry_ttl = LOBBY_TTL_LEDGERS * 2;
+    });
+
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    assert_eq!(lobby_ttl(&env, &client, lobby_id), LOBBY_TTL_LEDGERS);
+
+    advance_ledgers(&env, 1_000);
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    assert_eq!(lobby_ttl(&env, &client, lobby_id), LOBBY_TTL_LEDGERS);
+
+    advance_ledgers(&env, 1_000);
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+    assert_eq!(lobby_ttl(&env, &client, lobby_id), LOBBY_TTL_LEDGERS);
+
+    advance_ledgers(&env, 1_000);
+    attempt(&client, lobby_id, &player1, false);
+    assert_eq!(lobby_ttl(&env, &client, lobby_id), LOBBY_TTL_LEDGERS);
 }
 
 #[test]
-fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_waiting_lobby_expiry_and_cancel() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    // The creator may withdraw a lobby nobody has joined
+    let lonely = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    client.cancel_lobby(&lonely);
+    assert_eq!(stored_lobby(&client, lonely).status, Status::Cancelled);
+    let result = client.try_join_lobby(&lonely, &player2, &STAKE);
+    assert_farm_error(&result, Error::NotWaiting);
+
+    // Once joined, the lobby can only be cancelled after it expires unstarted
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    let result = client.try_cancel_lobby(&lobby_id);
+    assert_farm_error(&result, Error::NotExpired);
+
+    advance_ledgers(&env, WAITING_EXPIRY_LEDGERS + 1);
+    let result = client.try_set_commit(&lobby_id, &player1, &commitment(&env, 1));
+    assert_farm_error(&result, Error::LobbyExpired);
+    let other = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    advance_ledgers(&env, WAITING_EXPIRY_LEDGERS + 1);
+    let late = Address::generate(&env);
+    let result = client.try_join_lobby(&other, &late, &STAKE);
+    assert_farm_error(&result, Error::LobbyExpired);
+
+    client.cancel_lobby(&lobby_id);
+    assert_eq!(stored_lobby(&client, lobby_id).status, Status::Cancelled);
+    let result = client.try_cancel_lobby(&lobby_id);
+    assert_farm_error(&result, Error::NotWaiting);
+}
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+#[test]
+fn test_stalled_player_loses_abandoned_lobby() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    // Player 1 attempts a door; player 2 never shows up to answer it
+    advance_ledgers(&env, 100);
+    client.attempt_door(&lobby_id, &player1, &1, &1, &0);
+    let result = client.try_settle_abandoned(&lobby_id);
+    assert_farm_error(&result, Error::NotExpired);
+
+    advance_ledgers(&env, ABANDON_LEDGERS);
+    let result = client.try_settle_abandoned(&lobby_id);
+    assert_farm_error(&result, Error::NotExpired);
+
+    advance_ledgers(&env, 1);
+    client.settle_abandoned(&lobby_id);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Abandoned);
+    assert_eq!(lobby.winner, Some(player1.clone()));
+    let ended = hub.ended();
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended.get(0).unwrap().session_id, lobby_id);
+    assert!(ended.get(0).unwrap().player1_won);
+
+    // Settled lobbies take no more moves
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &2, &0);
+    assert_farm_error(&result, Error::LobbyInactive);
+    let result = client.try_settle_abandoned(&lobby_id);
+    assert_farm_error(&result, Error::LobbyInactive);
+}
 
-    // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+#[test]
+fn test_player_left_at_a_gate_loses_abandoned_lobby() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+    attempt(&client, lobby_id, &player1, true);
+    attempt(&client, lobby_id, &player2, true);
+    climb_to(&client, lobby_id, &player1, 5);
+    climb_to(&client, lobby_id, &player2, 4);
+
+    // Player 1 clears gate 5 and waits there for player 2
+    attempt(&client, lobby_id, &player1, true);
+    assert!(cleared(&stored_lobby(&client, lobby_id).p1, 5));
+
+    // Player 2 makes one more attempt later, then stops
+    advance_ledgers(&env, 500);
+    attempt(&client, lobby_id, &player2, false);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.p2.last_active, lobby.p1.last_active);
+
+    advance_ledgers(&env, ABANDON_LEDGERS);
+    let result = client.try_settle_abandoned(&lobby_id);
+    assert_farm_error(&result, Error::NotExpired);
+
+    // The game is waiting on player 2, so player 1 wins
+    advance_ledgers(&env, 1);
+    client.settle_abandoned(&lobby_id);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Abandoned);
+    assert_eq!(lobby.winner, Some(player1));
+    assert!(hub.ended().get(0).unwrap().player1_won);
+}
+
+#[test]
+fn test_abandoned_by_both_players_is_a_draw() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+
+    advance_ledgers(&env, ABANDON_LEDGERS + 1);
+    client.settle_abandoned(&lobby_id);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Abandoned);
+    assert!(lobby.winner.is_none());
+    assert!(hub.ended().is_empty());
+    assert_eq!(hub.drawn(), soroban_sdk::vec![&env, lobby_id]);
+}
 
-    // Second reveal should return same winner (idempotent)
-    let winner2 = client.reveal_winner(&session_id);
-    assert_eq!(winner, winner2);
+#[test]
+fn test_resign_hands_the_win_to_the_opponent() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let waiting = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    let result = client.try_resign(&waiting, &player1);
+    assert_farm_error(&result, Error::LobbyInactive);
+
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+    attempt(&client, lobby_id, &player1, true);
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&lobby_id, &outsider);
+    assert_farm_error(&result, Error::NotPlayer);
+
+    client.resign(&lobby_id, &player1);
+    assert_eq!(env.auths()[0].0, player1);
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Finished);
+    assert_eq!(lobby.winner, Some(player2.clone()));
+    let ended = hub.ended();
+    assert_eq!(ended.len(), 1);
+    assert!(!ended.get(0).unwrap().player1_won);
+
+    let result = client.try_resign(&lobby_id, &player2);
+    assert_farm_error(&result, Error::LobbyInactive);
+}
+
+#[test]
+fn test_abort_needs_both_players_and_refunds_the_session() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+    attempt(&client, lobby_id, &player2, false);
+
+    client.abort(&lobby_id);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2, "abort must be signed by both players");
+    assert_eq!(auths[0].0, player1);
+    assert_eq!(auths[1].0, player2);
+
+    let lobby = stored_lobby(&client, lobby_id);
+    assert_eq!(lobby.status, Status::Aborted);
+    assert!(lobby.winner.is_none());
+    assert_eq!(hub.cancelled(), soroban_sdk::vec![&env, lobby_id]);
+    assert!(hub.ended().is_empty());
+    assert!(hub.drawn().is_empty());
+
+    // Aborted lobbies take no more moves or settlements
+    let result = client.try_attempt_door(&lobby_id, &player1, &1, &1, &0);
+    assert_farm_error(&result, Error::LobbyInactive);
+    let result = client.try_resign(&lobby_id, &player1);
+    assert_farm_error(&result, Error::LobbyInactive);
+    let result = client.try_abort(&lobby_id);
+    assert_farm_error(&result, Error::LobbyInactive);
+    advance_ledgers(&env, ABANDON_LEDGERS + 1);
+    let result = client.try_settle_abandoned(&lobby_id);
+    assert_farm_error(&result, Error::LobbyInactive);
 }
 
 // ============================================================================
-// Multiple Games Tests
+// View Tests
 // ============================================================================
 
 #[test]
-fn test_multiple_games_independent() {
+fn test_progress_view_is_redacted() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
+    let lobby_id = client.create_lobby(&player1, &STAKE, &classic_dungeon(&env));
+    client.join_lobby(&lobby_id, &player2, &STAKE);
+    client.set_commit(&lobby_id, &player1, &commitment(&env, 1));
+
+    let progress = client.get_progress(&lobby_id).unwrap();
+    assert_eq!(progress.status, Status::Waiting);
+    assert!(progress.p1.committed);
+    assert!(!progress.p2.committed);
+
+    client.set_commit(&lobby_id, &player2, &commitment(&env, 2));
+    attempt(&client, lobby_id, &player1, true);
+    let progress = client.get_progress(&lobby_id).unwrap();
+    assert_eq!(
+        progress,
+        LobbyProgress {
+            status: Status::Active,
+            player1: player1.clone(),
+            player2: Some(player2.clone()),
+            p1: PlayerProgress {
+                floor: 1,
+                cleared_gates: 1,
+                committed: true,
+            },
+            p2: PlayerProgress {
+                floor: 1,
+                cleared_gates: 0,
+                committed: true,
+            },
+            depth: 10,
+            gate_floors: Vec::from_array(&env, [1, 5]),
+            winner: None,
+            stake: STAKE,
+        }
+    );
+    assert_eq!(client.get_config(&lobby_id), classic_dungeon(&env));
+    assert!(client.get_progress(&12345).is_none());
+}
 
-    let session1 = 20u32;
-    let session2 = 21u32;
+#[test]
+fn test_player_state_requires_that_players_auth() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby_id = start_active_lobby(&env, &client, &player1, &player2);
+    attempt(&client, lobby_id, &player2, false);
 
-    // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    let state = client.get_player_state(&lobby_id, &player2);
+    assert_eq!(
+        env.auths()[0].0,
+        player2,
+        "player state must be authorized by that player"
+    );
+    assert_eq!(state, stored_lobby(&client, lobby_id).p2);
+    assert_eq!(state.commit, Some(commitment(&env, 2)));
+    assert_eq!(state.last_nonce, 1);
 
-    // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+    let outsider = Address::generate(&env);
+    let result = client.try_get_player_state(&lobby_id, &outsider);
+    assert_farm_error(&result, Error::NotPlayer);
+}
 
-    // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
+// ============================================================================
+// Lobby Browser Tests
+// ============================================================================
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+fn open_ids(open: &Vec<OpenLobby>) -> Vec<u32> {
+    let mut ids = Vec::new(open.env());
+    for entry in open.iter() {
+        ids.push_back(entry.lobby_id);
+    }
+    ids
+}
 
-    // Verify both games are independent
-    let final_game1 = client.get_game(&session1);
-    let final_game2 = client.get_game(&session2);
+#[test]
+fn test_list_open_lobbies_filters_and_pages() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = classic_dungeon(&env);
+    let small = client.create_lobby(&player1, &100, &config);
+    let large = client.create_lobby(&player1, &500, &config);
+    let other = client.create_lobby(&player2, &300, &config);
 
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
+    let all = client.list_open_lobbies(&None, &None, &0, &10);
+    assert_eq!(open_ids(&all), vec![&env, small, large, other]);
+    assert_eq!(
+        all.get(1).unwrap(),
+        OpenLobby {
+            lobby_id: large,
+            player1: player1.clone(),
+            stake: 500,
+            depth: 10,
+            created_ledger: env.ledger().sequence(),
+        }
+    );
 
-    // Note: winning numbers could be the same by chance, so we just verify they're both set
-    assert!(final_game1.winning_number.is_some());
-    assert!(final_game2.winning_number.is_some());
+    let rich = client.list_open_lobbies(&Some(300), &None, &0, &10);
+    assert_eq!(open_ids(&rich), vec![&env, large, other]);
+    let mine = client.list_open_lobbies(&None, &Some(player1.clone()), &0, &10);
+    assert_eq!(open_ids(&mine), vec![&env, small, large]);
+    let both = client.list_open_lobbies(&Some(300), &Some(player1.clone()), &0, &10);
+    assert_eq!(open_ids(&both), vec![&env, large]);
+
+    // Pages count matching lobbies only
+    let page = client.list_open_lobbies(&None, &None, &1, &1);
+    assert_eq!(open_ids(&page), vec![&env, large]);
+    let page = client.list_open_lobbies(&Some(300), &None, &1, &10);
+    assert_eq!(open_ids(&page), vec![&env, other]);
+    assert!(client.list_open_lobbies(&None, &None, &3, &10).is_empty());
 }
 
 #[test]
-fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_open_lobbies_removed_on_join_cancel_and_expiry() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = classic_dungeon(&env);
+    let joined = client.create_lobby(&player1, &STAKE, &config);
+    let cancelled = client.create_lobby(&player1, &STAKE, &config);
+    advance_ledgers(&env, 10);
+    let later = client.create_lobby(&player1, &STAKE, &config);
+
+    client.join_lobby(&joined, &player2, &STAKE);
+    client.cancel_lobby(&cancelled);
+    let open = client.list_open_lobbies(&None, &None, &0, &10);
+    assert_eq!(open_ids(&open), vec![&env, later]);
+
+    // A lobby leaves the index once it can no longer be joined
+    advance_ledgers(&env, WAITING_EXPIRY_LEDGERS);
+    let open = client.list_open_lobbies(&None, &None, &0, &10);
+    assert_eq!(open_ids(&open), vec![&env, later]);
+    advance_ledgers(&env, 1);
+    assert!(client.list_open_lobbies(&None, &None, &0, &10).is_empty());
+}
 
-    let session_id = 15u32;
-    let points1 = 200_0000000;
-    let points2 = 50_0000000;
+#[test]
+fn test_open_lobby_index_is_bounded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = dungeon(&env, 1, &[]);
+    for _ in 0..MAX_OPEN_LOBBIES {
+        client.create_lobby(&Address::generate(&env), &STAKE, &config);
+    }
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    // A full index does not stop lobbies being created; the new one is just
+    // not listed and can still be joined by id
+    let unlisted = client.create_lobby(&player2, &STAKE, &config);
+    let listed = client.list_open_lobbies(&None, &Some(player2.clone()), &0, &10);
+    assert!(listed.is_empty());
+    client.join_lobby(&unlisted, &player1, &STAKE);
+    assert_eq!(
+        stored_lobby(&client, unlisted).player2,
+        Some(player1.clone())
+    );
 
-    let game = client.get_game(&session_id);
-    assert_eq!(game.player1_points, points1);
-    assert_eq!(game.player2_points, points2);
+    // Pages are capped, and expired lobbies free up room
+    let page = client.list_open_lobbies(&None, &None, &0, &MAX_OPEN_LOBBIES);
+    assert_eq!(page.len(), MAX_LOBBY_PAGE);
+    advance_ledgers(&env, WAITING_EXPIRY_LEDGERS + 1);
+    client.create_lobby(&player1, &STAKE, &config);
+    assert_eq!(client.list_open_lobbies(&None, &None, &0, &10).len(), 1);
+}
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
-    client.reveal_winner(&session_id);
+#[test]
+fn test_one_creator_cannot_flood_the_index() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = dungeon(&env, 1, &[]);
 
-    // Game completes successfully with asymmetric points
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    // Free lobbies from one account fill only that account's share
+    for _ in 0..MAX_OPEN_LOBBIES {
+        client.create_lobby(&player1, &0, &config);
+    }
+    let flooded = client.list_open_lobbies(&None, &None, &0, &MAX_LOBBY_PAGE);
+    assert_eq!(flooded.len(), MAX_OPEN_LOBBIES_PER_CREATOR);
+
+    // Everyone else still gets listed
+    let lobby_id = client.create_lobby(&player2, &STAKE, &config);
+    let listed = client.list_open_lobbies(&None, &Some(player2.clone()), &0, &10);
+    assert_eq!(open_ids(&listed), vec![&env, lobby_id]);
+
+    // A listed lobby leaving the index frees a slot for its creator
+    let first = flooded.get(0).unwrap().lobby_id;
+    client.cancel_lobby(&first);
+    let refill = client.create_lobby(&player1, &0, &config);
+    let mine = client.list_open_lobbies(&None, &Some(player1.clone()), &0, &10);
+    assert_eq!(mine.len(), MAX_OPEN_LOBBIES_PER_CREATOR);
+    assert_eq!(
+        mine.get(MAX_OPEN_LOBBIES_PER_CREATOR - 1).unwrap().lobby_id,
+        refill
+    );
 }
 
 // ============================================================================
-// Admin Function Tests
+// Admin Tests
 // ============================================================================
 
 #[test]
-fn test_upgrade_function_exists() {
-    let env = Env::default();
-    env.mock_all_auths();
+fn test_admin_can_update_hub() {
+    let (env, client, hub, _player1, _player2) = setup_test();
 
-    let admin = Address::generate(&env);
-    let hub_addr = env.register(MockGameHub, ());
-
-    // Deploy the-farm with admin
-    let contract_id = env.register(TheFarmContract, (&admin, &hub_addr));
-    let client = TheFarmContractClient::new(&env, &contract_id);
-
-    // Verify the upgrade function exists and can be called
-    // Note: We can't test actual upgrade without real WASM files
-    // The function will fail with MissingValue because the WASM hash doesn't exist
-    // But that's expected - we're just verifying the function signature is correct
-    let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let result = client.try_upgrade(&new_wasm_hash);
-
-    // Should fail with MissingValue (WASM doesn't exist) not NotAdmin
-    // This confirms the authorization check passed
-    assert!(result.is_err());
+    assert_eq!(client.get_hub(), hub.address);
+    let new_hub = Address::generate(&env);
+    client.set_hub(&new_hub);
+    assert_eq!(client.get_hub(), new_hub);
+
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
 }
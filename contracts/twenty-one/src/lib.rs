@@ -6,13 +6,56 @@
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
 //!
+//! A session is a best-of-N match: each hand is a round, and the first player to
+//! win `match_target` rounds wins the match. Every round is recorded in a round
+//! history that can be queried with `get_round_history`.
+//!
+//! Optional side bets (perfect pairs, 21+3, insurance) can be committed at
+//! `start_game`. They are judged on the opening hands of the first round and
+//! settled through the Game Hub, independently of the main hand. A session's
+//! seed is known to whoever picks the session ID, so a game with side bets is
+//! not dealt at `start_game`: each player first commits to 32 bytes of secret
+//! entropy (`commit_entropy`), and once both have committed each reveals it
+//! (`reveal_entropy`). The first round is dealt, and the bets settled, when the
+//! second reveal lands, so no bettor knows the opening hands before both
+//! players are bound to their entropy. A player who stops before the deal
+//! forfeits their side bets at `claim_timeout`.
+//!
+//! A table variant seats 2-6 players for a single hand (`start_table`). Seats are
+//! ranked by hand value once everyone has stuck or busted, and the ranking is
+//! submitted through the Game Hub's N-player interface. Once a table's deadline
+//! passes, `claim_table_timeout` ranks any seat that has not stuck below every
+//! other seat.
+//!
+//! A two-player match can also end early: `claim_timeout` settles a match a
+//! player has stopped playing, `resign` concedes it to the opponent, and `abort`
+//! (signed by both players) cancels it through the Game Hub with all points
+//! refunded. Tables have `table_resign`, which ranks the seat last, and
+//! `table_abort`, which every seated player must sign.
+//!
+//! **Replay:**
+//! Every deal, hit, stick and reveal is appended to a per-session action log
+//! (`get_action_log`). Each card is drawn by seeding the PRNG with a keccak256
+//! hash and taking `gen_range(1..=13)`, so a disputed game can be replayed card
+//! by card from the log:
+//! - Opening cards: `keccak256(base_seed || [seed_index, seat])`, where
+//!   `base_seed = keccak256(session_id || player1 || player2 || round)` (u32s
+//!   big-endian, addresses as strkeys). Tables hash `session_id` and every
+//!   seated player, with no round. The first round of a game with side bets
+//!   uses `keccak256(base_seed || player1_entropy || player2_entropy)` instead
+//!   (see `get_game` for the revealed entropy).
+//! - Hits: `keccak256(session_id || player || seed_index || round)`, where
+//!   `seed_index` is the hand size before the hit (u32 big-endian).
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use core::cmp::Ordering;
+
 use soroban_sdk::{
     Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
-    contractimpl, contracttype, vec
+    contractimpl, contracttype, vec, Vec
 };
 
 // Import GameHub contract interface
@@ -30,6 +73,22 @@ pub trait GameHub {
     );
 
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn cancel_game(env: Env, session_id: u32);
+
+    fn start_table_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    );
+
+    fn end_table_game(env: Env, session_id: u32, ranks: Vec<u32>);
+
+    fn settle_side_bet(env: Env, session_id: u32, player: Address, wager: i128, payout: i128);
 }
 
 // ============================================================================
@@ -47,10 +106,24 @@ pub enum Error {
     PlayerBusted = 5,
     BothPlayersNotStuck = 6,
     OpponentNotStuck = 7,
-    Draw = 8,
     SelfPlay = 9,
     RoundOverflow = 10,
     InvalidHandData = 11,
+    InvalidMaxRounds = 12,
+    InvalidMatchTarget = 13,
+    InvalidSeatCount = 14,
+    DuplicatePlayer = 15,
+    SeatsNotFinished = 16,
+    InvalidSideBet = 17,
+    DeadlineNotReached = 18,
+    NoIdlePlayer = 19,
+    InvalidTimeout = 20,
+    CardsNotDealt = 21,
+    CardsAlreadyDealt = 22,
+    AlreadyCommitted = 23,
+    OpponentNotCommitted = 24,
+    InvalidEntropy = 25,
+    AlreadyRevealed = 26,
 }
 
 // ============================================================================
@@ -68,6 +141,19 @@ pub enum Error {
 // Data Types
 // ============================================================================
 
+/// Outcome of a single round (or of the match, once the game has ended).
+///
+/// While the match is still open, any result means the round was scored and new
+/// hands were dealt for the next one; once `Game::drawn` is set, `Draw` means
+/// the match settled as a draw.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundResult {
+    Player1Won,
+    Player2Won,
+    Draw,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -81,14 +167,121 @@ pub struct Game {
     pub player2_stuck: bool,
     pub winner: Option<Address>,
     pub round: u32,
+    pub max_rounds: u32, // Rounds allowed before the match is settled on score
+    pub drawn: bool,
+    pub match_target: u32, // Round wins needed to win the match
+    pub player1_score: u32,
+    pub player2_score: u32,
+    pub timeout_ledgers: u32, // Ledgers each player has to act after the last action
+    pub deadline: u32,        // Ledger after which an idle player can be timed out
+    pub aborted: bool,        // Cancelled by both players; all points refunded
+    // Side bet games only: keccak256 commitments to, then the revealed,
+    // entropy mixed into the first deal
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    pub player1_entropy: Option<BytesN<32>>,
+    pub player2_entropy: Option<BytesN<32>>,
+}
+
+/// A completed round, as stored in the round history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundRecord {
+    pub round: u32,
+    pub player1_hand: Bytes,
+    pub player2_hand: Bytes,
+    pub player1_value: u32,
+    pub player2_value: u32,
+    pub result: RoundResult,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActionKind {
+    Deal,
+    Hit,
+    Stick,
+    Reveal,
+}
+
+/// An entry in a session's append-only action log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Action {
+    pub kind: ActionKind,
+    pub player: Option<Address>, // None for reveals
+    pub card: Option<u32>,       // Card drawn by a deal or hit
+    pub round: u32,
+    pub seed_index: u32, // Card index the seed was derived for (see module docs)
+}
+
+/// Side bets that can be committed at `start_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SideBetKind {
+    /// The player's first two cards are a pair
+    PerfectPairs,
+    /// The player's first two cards plus the opponent's up card make trips or a straight
+    TwentyOnePlusThree,
+    /// The opponent's up card is an Ace and their opening hand is Ace + ten-value card
+    Insurance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SideBet {
+    pub player: Address,
+    pub kind: SideBetKind,
+    pub wager: i128,
+}
+
+/// A settled side bet. `payout` is the total returned to the player:
+/// 0 when lost, the wager when void, and wager * (odds + 1) when won.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SideBetResult {
+    pub player: Address,
+    pub kind: SideBetKind,
+    pub wager: i128,
+    pub payout: i128,
+}
+
+/// One player's seat at a multi-seat table
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Seat {
+    pub player: Address,
+    pub points: i128,
+    pub hand: Bytes, // Each byte represents a card (1-13)
+    pub stuck: bool,
+    pub busted: bool,
+    pub forfeited: bool, // Timed out or resigned; ranked below every other seat
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Table {
+    pub seats: Vec<Seat>,
+    pub ranks: Option<Vec<u32>>, // Rank per seat (1 = best), set once the table is revealed
+    pub timeout_ledgers: u32, // Ledgers each seat has to act after the last action
+    pub deadline: u32,        // Ledger after which idle seats can be timed out
+    pub aborted: bool,        // Cancelled by every seat; all points refunded
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
+    Table(u32),
+    SideBets(u32),
+    PendingSideBets(u32),
+    ActionLog(u32),
+    RoundHistory(u32),
     GameHubAddress,
     Admin,
+    MaxRounds,
+    MatchTarget,
+    TimeoutLedgers,
 }
 
 // ============================================================================
@@ -101,6 +294,37 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Rounds dealt before an undecided match is settled on score, unless the admin
+/// configures a different limit via `set_max_rounds`
+const DEFAULT_MAX_ROUNDS: u32 = 5;
+
+/// Round wins needed to take the match (1 = single hand), unless the admin
+/// configures a different target via `set_match_target`
+const DEFAULT_MATCH_TARGET: u32 = 1;
+
+/// Ledgers a player has to act before the opponent can claim a timeout
+/// (~1 day), unless the admin configures a different timeout via
+/// `set_timeout_ledgers`
+const DEFAULT_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Longest timeout `set_timeout_ledgers` accepts: a game's storage does not
+/// outlive `GAME_TTL_LEDGERS`, so a longer deadline could never be claimed
+const MAX_TIMEOUT_LEDGERS: u32 = GAME_TTL_LEDGERS;
+
+// Side bet payout tables (odds "to 1")
+/// Perfect pairs: first two cards share a rank (suits are not modeled)
+const PERFECT_PAIRS_ODDS: i128 = 6;
+/// 21+3: three of a kind
+const TRIPS_ODDS: i128 = 30;
+/// 21+3: three consecutive ranks (Ace plays low or high)
+const STRAIGHT_ODDS: i128 = 10;
+/// Insurance: opponent shows an Ace and holds a ten-value card
+const INSURANCE_ODDS: i128 = 2;
+
+/// Seat limits for the multi-seat table variant
+const MIN_SEATS: u32 = 2;
+const MAX_SEATS: u32 = 6;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -134,6 +358,247 @@ fn deal_card(env: &Env, seed: BytesN<32>) -> u8 {
     env.prng().gen_range::<u64>(1..=13) as u8
 }
 
+/// Check whether three card ranks form a straight (Ace low or high)
+fn is_straight(a: u32, b: u32, c: u32) -> bool {
+    let (mut lo, mut mid, mut hi) = (a, b, c);
+    if lo > mid {
+        core::mem::swap(&mut lo, &mut mid);
+    }
+    if mid > hi {
+        core::mem::swap(&mut mid, &mut hi);
+    }
+    if lo > mid {
+        core::mem::swap(&mut lo, &mut mid);
+    }
+    (mid == lo + 1 && hi == mid + 1) || (lo == 1 && mid == 12 && hi == 13)
+}
+
+/// Work out the total returned for a side bet from the opening hands.
+/// The opponent's up card is the first card of their hand.
+fn side_bet_payout(
+    kind: SideBetKind,
+    wager: i128,
+    own_hand: &Bytes,
+    opponent_hand: &Bytes,
+) -> Result<i128, Error> {
+    let card = |hand: &Bytes, i: u32| hand.get(i).map(u32::from).ok_or(Error::InvalidHandData);
+    let (first, second) = (card(own_hand, 0)?, card(own_hand, 1)?);
+    let (up_card, hole_card) = (card(opponent_hand, 0)?, card(opponent_hand, 1)?);
+
+    let odds = match kind {
+        SideBetKind::PerfectPairs => (first == second).then_some(PERFECT_PAIRS_ODDS),
+        SideBetKind::TwentyOnePlusThree => {
+            if first == second && second == up_card {
+                Some(TRIPS_ODDS)
+            } else if is_straight(first, second, up_card) {
+                Some(STRAIGHT_ODDS)
+            } else {
+                None
+            }
+        }
+        SideBetKind::Insurance => {
+            if up_card != 1 {
+                // Insurance is only offered against an Ace - void the bet
+                return Ok(wager);
+            }
+            (card_value(hole_card) == 10).then_some(INSURANCE_ODDS)
+        }
+    };
+
+    match odds {
+        Some(odds) => wager
+            .checked_mul(odds + 1)
+            .ok_or(Error::InvalidSideBet),
+        None => Ok(0),
+    }
+}
+
+/// Derive the base seed for a round's opening hands.
+///
+/// Seed components (all deterministic and identical between sim/submit):
+/// 1. Session ID - unique per game
+/// 2. Player addresses - both players contribute
+/// 3. Round number - each redeal gets fresh cards
+///
+/// Note: We do NOT include ledger sequence or timestamp because those differ
+/// between simulation and submission.
+fn round_seed(env: &Env, session_id: u32, game: &Game) -> BytesN<32> {
+    let mut seed_bytes = Bytes::new(env);
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    seed_bytes.append(&game.player1.to_string().to_bytes());
+    seed_bytes.append(&game.player2.to_string().to_bytes());
+    seed_bytes.append(&Bytes::from_array(env, &game.round.to_be_bytes()));
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Mix both players' revealed entropy into the first round's base seed.
+/// Used for games with side bets, so the opening hands are unknown until
+/// both players have revealed.
+fn shuffled_seed(
+    env: &Env,
+    base_seed: &BytesN<32>,
+    player1_entropy: &BytesN<32>,
+    player2_entropy: &BytesN<32>,
+) -> BytesN<32> {
+    let mut seed_bytes = Bytes::from(base_seed.clone());
+    seed_bytes.append(&Bytes::from(player1_entropy.clone()));
+    seed_bytes.append(&Bytes::from(player2_entropy.clone()));
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// How far a player has got through the side bet shuffle:
+/// 0 = idle, 1 = committed, 2 = revealed
+fn shuffle_stage(commitment: &Option<BytesN<32>>, entropy: &Option<BytesN<32>>) -> u32 {
+    if entropy.is_some() {
+        2
+    } else if commitment.is_some() {
+        1
+    } else {
+        0
+    }
+}
+
+/// Derive the base seed for a table's opening hands.
+/// Same components as `round_seed`, with every seated player contributing.
+fn table_seed(env: &Env, session_id: u32, players: &Vec<Address>) -> BytesN<32> {
+    let mut seed_bytes = Bytes::new(env);
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    for player in players.iter() {
+        seed_bytes.append(&player.to_string().to_bytes());
+    }
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Deal a 2-card opening hand for one seat from the base seed.
+/// Seats are numbered from 1 (player 1 and 2 in a two-player game).
+fn deal_opening_hand(env: &Env, base_seed: &BytesN<32>, seat: u8) -> Bytes {
+    let mut hand = Bytes::new(env);
+    for i in 0..2 {
+        let mut card_seed_bytes = Bytes::new(env);
+        card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+        card_seed_bytes.append(&Bytes::from_array(env, &[i, seat])); // [card_index, seat]
+        let card_seed = env.crypto().keccak256(&card_seed_bytes);
+        hand.push_back(deal_card(env, card_seed.into()));
+    }
+    hand
+}
+
+/// Deal one more card into a seat's hand ("hit") and return the new hand value.
+/// The card seed is derived from the session, the player, the current hand size
+/// and the round, so each draw is unique and replayable.
+fn hit_seat(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    hand: &mut Bytes,
+    round: u32,
+) -> Result<u32, Error> {
+    let mut seed_bytes = Bytes::new(env);
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    seed_bytes.append(&player.to_string().to_bytes());
+    seed_bytes.append(&Bytes::from_array(env, &hand.len().to_be_bytes()));
+    seed_bytes.append(&Bytes::from_array(env, &round.to_be_bytes()));
+
+    let card_seed = env.crypto().keccak256(&seed_bytes);
+    hand.push_back(deal_card(env, card_seed.into()));
+
+    calculate_hand_value(hand)
+}
+
+/// Append Deal actions for an opening hand
+fn push_deal_actions(actions: &mut Vec<Action>, player: &Address, hand: &Bytes, round: u32) {
+    for (i, card) in hand.iter().enumerate() {
+        actions.push_back(Action {
+            kind: ActionKind::Deal,
+            player: Some(player.clone()),
+            card: Some(card.into()),
+            round,
+            seed_index: i as u32,
+        });
+    }
+}
+
+/// Build a single action without a card (stick or reveal)
+fn plain_action(kind: ActionKind, player: Option<Address>, round: u32) -> Action {
+    Action {
+        kind,
+        player,
+        card: None,
+        round,
+        seed_index: 0,
+    }
+}
+
+/// Build the Hit action for the card just added to the end of a hand
+fn hit_action(player: &Address, hand: &Bytes, round: u32) -> Action {
+    let seed_index = hand.len() - 1;
+    Action {
+        kind: ActionKind::Hit,
+        player: Some(player.clone()),
+        card: hand.get(seed_index).map(u32::from),
+        round,
+        seed_index,
+    }
+}
+
+/// Append actions to a session's action log, keeping it alive with the game
+fn record_actions(env: &Env, session_id: u32, actions: Vec<Action>) {
+    let key = DataKey::ActionLog(session_id);
+    let mut log: Vec<Action> = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    log.append(&actions);
+    env.storage().temporary().set(&key, &log);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Rank table seats by hand value (1 = best). Seats with equal values share a
+/// rank, busted seats share the rank below every standing seat, and forfeited
+/// seats share the rank below every busted seat.
+fn rank_seats(seats: &Vec<Seat>) -> Result<Vec<u32>, Error> {
+    let env = seats.env();
+    let mut values: Vec<u32> = Vec::new(env);
+    let mut standing = 0u32;
+    let mut busted = 0u32;
+    for seat in seats.iter() {
+        values.push_back(calculate_hand_value(&seat.hand)?);
+        if seat.forfeited {
+            continue;
+        }
+        if seat.busted {
+            busted += 1;
+        } else {
+            standing += 1;
+        }
+    }
+
+    let mut ranks: Vec<u32> = Vec::new(env);
+    for (i, seat) in seats.iter().enumerate() {
+        if seat.forfeited {
+            ranks.push_back(standing + busted + 1);
+            continue;
+        }
+        if seat.busted {
+            ranks.push_back(standing + 1);
+            continue;
+        }
+        let value = values.get_unchecked(i as u32);
+        let mut better = 0u32;
+        for (j, other) in seats.iter().enumerate() {
+            let other_standing = !other.busted && !other.forfeited;
+            if other_standing && values.get_unchecked(j as u32) > value {
+                better += 1;
+            }
+        }
+        ranks.push_back(better + 1);
+    }
+    Ok(ranks)
+}
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -158,7 +623,9 @@ impl TwentyOneContract {
 
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
-    /// Each player is dealt 2 cards to start.
+    /// Each player is dealt 2 cards to start the first round of the match. With
+    /// side bets, the deal waits for both players' entropy (see
+    /// `commit_entropy`), and the bets settle when the cards are dealt.
     ///
     /// **CRITICAL:** This method requires authorization from THIS contract (not players).
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
@@ -169,6 +636,10 @@ impl TwentyOneContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `side_bets` - Optional side bets (at most one of each kind per player)
+    ///
+    /// A player placing side bets also authorizes their own bets, in order, as
+    /// a third argument after `session_id` and their points.
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -176,15 +647,36 @@ impl TwentyOneContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        side_bets: Vec<SideBet>,
     ) -> Result<(), Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             return Err(Error::SelfPlay);
         }
 
+        // Validate side bets and split them per player
+        let mut player1_bets: Vec<SideBet> = Vec::new(&env);
+        let mut player2_bets: Vec<SideBet> = Vec::new(&env);
+        for bet in side_bets.iter() {
+            if bet.wager <= 0 {
+                return Err(Error::InvalidSideBet);
+            }
+            let bets = if bet.player == player1 {
+                &mut player1_bets
+            } else if bet.player == player2 {
+                &mut player2_bets
+            } else {
+                return Err(Error::NotPlayer);
+            };
+            if bets.iter().any(|other| other.kind == bet.kind) {
+                return Err(Error::InvalidSideBet);
+            }
+            bets.push_back(bet);
+        }
+
         // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        Self::require_stake_auth(&env, session_id, &player1, player1_points, &player1_bets);
+        Self::require_stake_auth(&env, session_id, &player2, player2_points, &player2_bets);
 
         // Get GameHub address
         let game_hub_addr: Address = env
@@ -207,56 +699,55 @@ impl TwentyOneContract {
             &player2_points,
         );
 
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Create game
-        let game = Game {
+        // Snapshot the match rules so admin changes don't affect games in progress
+        let max_rounds: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxRounds)
+            .unwrap_or(DEFAULT_MAX_ROUNDS);
+        let match_target: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MatchTarget)
+            .unwrap_or(DEFAULT_MATCH_TARGET);
+        let timeout_ledgers: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS);
+
+        // Create game with empty hands, then deal round 1
+        let mut game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
+            player1_hand: Bytes::new(&env),
+            player2_hand: Bytes::new(&env),
             player1_stuck: false,
             player2_stuck: false,
             winner: None,
             round: 1,
+            max_rounds,
+            drawn: false,
+            aborted: false,
+            match_target,
+            player1_score: 0,
+            player2_score: 0,
+            timeout_ledgers,
+            deadline: env.ledger().sequence() + timeout_ledgers,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_entropy: None,
+            player2_entropy: None,
         };
 
+        // Deal initial hands (2 cards each), unless side bets ride on them
+        if side_bets.is_empty() {
+            let base_seed = round_seed(&env, session_id, &game);
+            Self::deal_round(&env, session_id, &mut game, &base_seed);
+        }
+
         // Store game in temporary storage with 30-day TTL
         let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
@@ -266,13 +757,145 @@ impl TwentyOneContract {
             .temporary()
             .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
+        // Start with an empty round history, retained alongside the game
+        let history_key = DataKey::RoundHistory(session_id);
+        env.storage()
+            .temporary()
+            .set(&history_key, &Vec::<RoundRecord>::new(&env));
+        env.storage()
+            .temporary()
+            .extend_ttl(&history_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Hold side bets until the opening hands are dealt
+        if !side_bets.is_empty() {
+            let side_bets_key = DataKey::PendingSideBets(session_id);
+            env.storage().temporary().set(&side_bets_key, &side_bets);
+            env.storage()
+                .temporary()
+                .extend_ttl(&side_bets_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
         // Event emitted by GameHub contract (GameStarted)
 
         Ok(())
     }
 
+    /// Commit to the entropy a player mixes into the first deal of a game with
+    /// side bets. The commitment is the keccak256 hash of 32 secret bytes,
+    /// revealed with `reveal_entropy` once both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - keccak256 hash of the player's entropy
+    pub fn commit_entropy(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.player1_hand.is_empty() {
+            return Err(Error::CardsAlreadyDealt);
+        }
+
+        let slot = if player == game.player1 {
+            &mut game.player1_commitment
+        } else if player == game.player2 {
+            &mut game.player2_commitment
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if slot.is_some() {
+            return Err(Error::AlreadyCommitted);
+        }
+        *slot = Some(commitment);
+
+        // Every action restarts the clock
+        game.deadline = env.ledger().sequence() + game.timeout_ledgers;
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the entropy committed with `commit_entropy`. Neither player can
+    /// reveal until both have committed, so the last reveal cannot be chosen
+    /// to steer the deal. Once both players have revealed, the first round is
+    /// dealt from a seed mixing both entropies and the side bets are settled.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `entropy` - The 32 bytes whose keccak256 hash was committed
+    pub fn reveal_entropy(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        entropy: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.player1_hand.is_empty() {
+            return Err(Error::CardsAlreadyDealt);
+        }
+        if game.player1_commitment.is_none() || game.player2_commitment.is_none() {
+            return Err(Error::OpponentNotCommitted);
+        }
+
+        let (commitment, slot) = if player == game.player1 {
+            (&game.player1_commitment, &mut game.player1_entropy)
+        } else if player == game.player2 {
+            (&game.player2_commitment, &mut game.player2_entropy)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if slot.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(entropy.clone())).into();
+        if commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidEntropy);
+        }
+        *slot = Some(entropy);
+
+        // Deal the first round and settle the side bets once both have revealed
+        if let (Some(entropy1), Some(entropy2)) = (&game.player1_entropy, &game.player2_entropy) {
+            let base_seed = round_seed(&env, session_id, &game);
+            let seed = shuffled_seed(&env, &base_seed, entropy1, entropy2);
+            Self::deal_round(&env, session_id, &mut game, &seed);
+            Self::settle_side_bets(&env, session_id, &game, None)?;
+        }
+
+        // Every action restarts the clock
+        game.deadline = env.ledger().sequence() + game.timeout_ledgers;
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Player draws another card ("hit").
-    /// If the player's hand value exceeds 21, they bust and lose immediately.
+    /// If the player's hand value exceeds 21, they bust and lose the round immediately.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -288,10 +911,13 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner, not drawn and not aborted)
+        if game.winner.is_some() || game.drawn || game.aborted {
             return Err(Error::GameAlreadyEnded);
         }
+        if game.player1_hand.is_empty() {
+            return Err(Error::CardsNotDealt);
+        }
 
         // Check player hasn't stuck yet
         let is_player1 = player == game.player1;
@@ -309,60 +935,30 @@ impl TwentyOneContract {
             return Err(Error::AlreadyStuck);
         }
 
-        // Generate seed for new card based on current hand size
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player.to_string().to_bytes());
-
-        let card_count = if is_player1 {
-            game.player1_hand.len()
+        // Deal a new card into the player's hand
+        let round = game.round;
+        let hand = if is_player1 {
+            &mut game.player1_hand
         } else {
-            game.player2_hand.len()
+            &mut game.player2_hand
         };
-
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
-        seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-
-        let card_seed = env.crypto().keccak256(&seed_bytes);
-        let new_card = deal_card(&env, card_seed.into());
-
-        // Add card to player's hand
-        if is_player1 {
-            game.player1_hand.push_back(new_card);
-
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player1_hand)?;
-            if hand_value > 21 {
-                // Player 1 busted, player 2 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
-
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player2.clone());
-                env.storage().temporary().set(&key, &game);
-
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
-        } else {
-            game.player2_hand.push_back(new_card);
-
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player2_hand)?;
-            if hand_value > 21 {
-                // Player 2 busted, player 1 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
-
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player1.clone());
-                env.storage().temporary().set(&key, &game);
-
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+        let hand_value = hit_seat(&env, session_id, &player, hand, round)?;
+        record_actions(&env, session_id, vec![&env, hit_action(&player, hand, round)]);
+
+        // Check if player busted - the opponent takes the round
+        // Caller should check game.winner / game.round to see what happened
+        if hand_value > 21 {
+            let result = if is_player1 {
+                RoundResult::Player2Won
+            } else {
+                RoundResult::Player1Won
+            };
+            Self::finish_round(&env, session_id, &mut game, result)?;
         }
 
+        // Every action restarts the clock
+        game.deadline = env.ledger().sequence() + game.timeout_ledgers;
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
 
@@ -386,10 +982,13 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner, not drawn and not aborted)
+        if game.winner.is_some() || game.drawn || game.aborted {
             return Err(Error::GameAlreadyEnded);
         }
+        if game.player1_hand.is_empty() {
+            return Err(Error::CardsNotDealt);
+        }
 
         // Mark player as stuck
         if player == game.player1 {
@@ -406,23 +1005,35 @@ impl TwentyOneContract {
             return Err(Error::NotPlayer);
         }
 
+        let action = plain_action(ActionKind::Stick, Some(player), game.round);
+        record_actions(&env, session_id, vec![&env, action]);
+
+        // Every action restarts the clock
+        game.deadline = env.ledger().sequence() + game.timeout_ledgers;
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to GameHub.
+    /// Reveal the winner of the current round.
     /// Can only be called after both players have stuck.
-    /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// This calculates hand values and determines the round winner (closest to 21).
+    ///
+    /// The round is scored and recorded in the round history. If a player has
+    /// reached the match target, the outcome is submitted to the GameHub;
+    /// otherwise new hands are dealt for the next round. A tied round scores for
+    /// nobody. Once the game's `max_rounds` is reached, the match is settled on
+    /// score (a level score settles as a draw through the GameHub).
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `RoundResult` - The result of the round just played, or of the match if
+    ///   it has already ended
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<RoundResult, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -431,9 +1042,19 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
+        // Check if game already ended (has a winner or settled as a draw)
         if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+            return Ok(if *winner == game.player1 {
+                RoundResult::Player1Won
+            } else {
+                RoundResult::Player2Won
+            });
+        }
+        if game.drawn {
+            return Ok(RoundResult::Draw);
+        }
+        if game.aborted {
+            return Err(Error::GameAlreadyEnded);
         }
 
         // Check both players have stuck
@@ -445,95 +1066,267 @@ impl TwentyOneContract {
         let player1_value = calculate_hand_value(&game.player1_hand)?;
         let player2_value = calculate_hand_value(&game.player2_hand)?;
 
-        // Determine winner (closest to 21 without going over)
+        // Determine round winner (closest to 21 without going over)
         // Note: Bust conditions are already handled in hit(), so values should be <= 21
-        let winner = if player1_value > player2_value {
-            // Player 1 is closer to 21
-            game.player1.clone()
+        let result = if player1_value > player2_value {
+            RoundResult::Player1Won
         } else if player2_value > player1_value {
-            // Player 2 is closer to 21
-            game.player2.clone()
+            RoundResult::Player2Won
         } else {
-            // Draw - deal new hands and continue
-            game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
-
-            // Reset stuck flags
-            game.player1_stuck = false;
-            game.player2_stuck = false;
-
-            // Clear hands
-            game.player1_hand = Bytes::new(&env);
-            game.player2_hand = Bytes::new(&env);
-
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
-            }
-
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
-            }
+            RoundResult::Draw
+        };
 
-            // Store updated game and return error to indicate draw
-            env.storage().temporary().set(&key, &game);
+        let action = plain_action(ActionKind::Reveal, None, game.round);
+        record_actions(&env, session_id, vec![&env, action]);
 
-            return Err(Error::Draw);
-        };
+        Self::finish_round(&env, session_id, &mut game, result)?;
 
-        // Call GameHub FIRST (before setting winner)
-        let player1_won = winner == game.player1;
-        Self::end_game_with_hub(&env, session_id, player1_won)?;
+        // The next round's hands are dealt, so the clock restarts
+        game.deadline = env.ledger().sequence() + game.timeout_ledgers;
 
-        // Only update game with winner AFTER GameHub succeeds
-        game.winner = Some(winner.clone());
+        // Store updated game - a drawn round is a successful result, not an error
         env.storage().temporary().set(&key, &game);
 
-        Ok(winner)
+        Ok(result)
     }
 
-    /// Get game information.
+    /// Settle a match whose deadline has passed with a player still to stick.
+    /// If only one player has stuck, they win the match by forfeit; if neither
+    /// has, the match is settled as a draw. Once both have stuck, use
+    /// `reveal_winner` instead. Anyone may call this.
+    ///
+    /// Before a side bet game is dealt, the player further behind in the
+    /// entropy exchange (`commit_entropy`, then `reveal_entropy`) loses the
+    /// match and their side bets, and the opponent's side bets are void; if
+    /// both are equally far along, the match is a draw and every bet is void.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Game` - The game state (includes hands and winner after game ends)
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+    /// * `RoundResult` - The result of the match
+    pub fn claim_timeout(env: Env, session_id: u32) -> Result<RoundResult, Error> {
         let key = DataKey::Game(session_id);
-        env.storage()
+        let mut game: Game = env
+            .storage()
             .temporary()
             .get(&key)
-            .ok_or(Error::GameNotFound)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let result = if game.player1_hand.is_empty() {
+            let stage1 = shuffle_stage(&game.player1_commitment, &game.player1_entropy);
+            let stage2 = shuffle_stage(&game.player2_commitment, &game.player2_entropy);
+            match stage1.cmp(&stage2) {
+                Ordering::Equal => RoundResult::Draw,
+                Ordering::Greater => RoundResult::Player1Won,
+                Ordering::Less => RoundResult::Player2Won,
+            }
+        } else {
+            match (game.player1_stuck, game.player2_stuck) {
+                (true, true) => return Err(Error::NoIdlePlayer),
+                (false, false) => RoundResult::Draw,
+                (true, false) => RoundResult::Player1Won,
+                (false, true) => RoundResult::Player2Won,
+            }
+        };
+        let forfeited = match result {
+            RoundResult::Player1Won => Some(game.player2.clone()),
+            RoundResult::Player2Won => Some(game.player1.clone()),
+            RoundResult::Draw => None,
+        };
+        Self::settle_side_bets(&env, session_id, &game, forfeited.as_ref())?;
+        match result {
+            RoundResult::Draw => {
+                // Call GameHub FIRST (before marking the game drawn)
+                Self::end_game_draw_with_hub(&env, session_id)?;
+                game.drawn = true;
+            }
+            _ => {
+                // Call GameHub FIRST (before setting winner)
+                let player1_won = result == RoundResult::Player1Won;
+                Self::end_game_with_hub(&env, session_id, player1_won)?;
+                game.winner = Some(if player1_won {
+                    game.player1.clone()
+                } else {
+                    game.player2.clone()
+                });
+            }
+        }
+        env.storage().temporary().set(&key, &game);
+
+        Ok(result)
     }
 
-    /// Get the current hand value for a player.
+    /// Concede the match. The opponent wins and the session is settled
+    /// through the Game Hub. Resigning before a side bet game is dealt also
+    /// forfeits the resigning player's side bets and voids the opponent's.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player
+    /// * `player` - Address of the resigning player
     ///
     /// # Returns
-    /// * `u32` - The total value of the player's hand
-    pub fn get_hand_value(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+    /// * `RoundResult` - The result of the match
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<RoundResult, Error> {
+        player.require_auth();
+
         let key = DataKey::Game(session_id);
-        let game: Game = env
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let result = if player == game.player1 {
+            RoundResult::Player2Won
+        } else if player == game.player2 {
+            RoundResult::Player1Won
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        Self::settle_side_bets(&env, session_id, &game, Some(&player))?;
+
+        // Call GameHub FIRST (before setting winner)
+        let player1_won = result == RoundResult::Player1Won;
+        Self::end_game_with_hub(&env, session_id, player1_won)?;
+        game.winner = Some(if player1_won {
+            game.player1.clone()
+        } else {
+            game.player2.clone()
+        });
+        env.storage().temporary().set(&key, &game);
+
+        Ok(result)
+    }
+
+    /// Cancel the match by agreement. Both players must sign; the session is
+    /// cancelled through the Game Hub with no result and all points refunded.
+    /// Side bets settled at the deal are not affected; aborting before the
+    /// deal voids them.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn abort(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.player1.require_auth();
+        game.player2.require_auth();
+
+        if game.winner.is_some() || game.drawn || game.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        Self::settle_side_bets(&env, session_id, &game, None)?;
+
+        // Call GameHub FIRST (before marking the game aborted)
+        Self::cancel_game_with_hub(&env, session_id)?;
+        game.aborted = true;
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Get game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state (includes hands and winner after game ends)
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        let key = DataKey::Game(session_id);
+        env.storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)
+    }
+
+    /// Get the history of completed rounds in a match.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<RoundRecord>` - Completed rounds, oldest first
+    pub fn get_round_history(env: Env, session_id: u32) -> Result<Vec<RoundRecord>, Error> {
+        if !env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::GameNotFound);
+        }
+
+        Ok(env
+            .storage()
+            .temporary()
+            .get(&DataKey::RoundHistory(session_id))
+            .unwrap_or(Vec::new(&env)))
+    }
+
+    /// Get the settled side bets of a game. Empty until the bets settle when
+    /// the first round is dealt (or the game ends before the deal).
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<SideBetResult>` - Side bets in the order they were placed
+    pub fn get_side_bets(env: Env, session_id: u32) -> Result<Vec<SideBetResult>, Error> {
+        if !env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::GameNotFound);
+        }
+
+        Ok(env
+            .storage()
+            .temporary()
+            .get(&DataKey::SideBets(session_id))
+            .unwrap_or(Vec::new(&env)))
+    }
+
+    /// Get the append-only action log of a game or table.
+    /// Together with the seed derivation in the module docs, this is enough to
+    /// replay a session card by card.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game or table
+    ///
+    /// # Returns
+    /// * `Vec<Action>` - Every deal, hit, stick and reveal, oldest first
+    pub fn get_action_log(env: Env, session_id: u32) -> Result<Vec<Action>, Error> {
+        let storage = env.storage().temporary();
+        if !storage.has(&DataKey::Game(session_id)) && !storage.has(&DataKey::Table(session_id)) {
+            return Err(Error::GameNotFound);
+        }
+
+        Ok(storage
+            .get(&DataKey::ActionLog(session_id))
+            .unwrap_or(Vec::new(&env)))
+    }
+
+    /// Get the current hand value for a player.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `u32` - The total value of the player's hand
+    pub fn get_hand_value(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+        let key = DataKey::Game(session_id);
+        let game: Game = env
             .storage()
             .temporary()
             .get(&key)
@@ -548,10 +1341,534 @@ impl TwentyOneContract {
         }
     }
 
+    // ========================================================================
+    // Table (Multi-Seat) Functions
+    // ========================================================================
+
+    /// Start a single-hand table game for 2-6 players with points.
+    /// This creates a session in the Game Hub through its N-player interface and
+    /// locks points before dealing 2 cards to every seat.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `players` - Seated players, in seat order
+    /// * `points` - Points amount committed by each player, in seat order
+    pub fn start_table(
+        env: Env,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    ) -> Result<(), Error> {
+        let seat_count = players.len();
+        if !(MIN_SEATS..=MAX_SEATS).contains(&seat_count) || points.len() != seat_count {
+            return Err(Error::InvalidSeatCount);
+        }
+
+        // Every seat must be a different player
+        for i in 0..seat_count {
+            for j in (i + 1)..seat_count {
+                if players.get_unchecked(i) == players.get_unchecked(j) {
+                    return Err(Error::DuplicatePlayer);
+                }
+            }
+        }
+
+        // Require authentication from every player (they consent to committing points)
+        for (player, player_points) in players.iter().zip(points.iter()) {
+            player.require_auth_for_args(vec![
+                &env,
+                session_id.into_val(&env),
+                player_points.into_val(&env),
+            ]);
+        }
+
+        // Call the Game Hub to start the session and lock points
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_table_game(
+            &env.current_contract_address(),
+            &session_id,
+            &players,
+            &points,
+        );
+
+        // Deal 2 cards to every seat
+        let base_seed = table_seed(&env, session_id, &players);
+        let mut seats: Vec<Seat> = Vec::new(&env);
+        let mut actions = Vec::new(&env);
+        for (i, (player, player_points)) in players.iter().zip(points.iter()).enumerate() {
+            let hand = deal_opening_hand(&env, &base_seed, i as u8 + 1);
+            push_deal_actions(&mut actions, &player, &hand, 1);
+            seats.push_back(Seat {
+                player,
+                points: player_points,
+                hand,
+                stuck: false,
+                busted: false,
+                forfeited: false,
+            });
+        }
+        record_actions(&env, session_id, actions);
+
+        // Snapshot the timeout so admin changes don't affect tables in progress
+        let timeout_ledgers: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS);
+
+        let table = Table {
+            seats,
+            ranks: None,
+            timeout_ledgers,
+            deadline: env.ledger().sequence() + timeout_ledgers,
+            aborted: false,
+        };
+
+        // Store table in temporary storage with 30-day TTL
+        let table_key = DataKey::Table(session_id);
+        env.storage().temporary().set(&table_key, &table);
+        env.storage()
+            .temporary()
+            .extend_ttl(&table_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Seated player draws another card ("hit").
+    /// If the hand value exceeds 21, the seat busts and is out of the hand.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    /// * `player` - Address of the player drawing a card
+    pub fn table_hit(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Table(session_id);
+        let mut table: Table = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        if table.ranks.is_some() || table.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let index = Self::seat_index(&table, &player)?;
+        let mut seat = table.seats.get_unchecked(index);
+        if seat.stuck {
+            return Err(Error::AlreadyStuck);
+        }
+
+        let hand_value = hit_seat(&env, session_id, &player, &mut seat.hand, 1)?;
+        record_actions(&env, session_id, vec![&env, hit_action(&player, &seat.hand, 1)]);
+        if hand_value > 21 {
+            // A busted seat is finished for the hand
+            seat.busted = true;
+            seat.stuck = true;
+        }
+        table.seats.set(index, seat);
+
+        // Every action restarts the clock for the seats still to act
+        table.deadline = env.ledger().sequence() + table.timeout_ledgers;
+        env.storage().temporary().set(&key, &table);
+
+        Ok(())
+    }
+
+    /// Seated player chooses to stick (end their turn with current hand).
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    /// * `player` - Address of the player sticking
+    pub fn table_stick(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Table(session_id);
+        let mut table: Table = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        if table.ranks.is_some() || table.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let index = Self::seat_index(&table, &player)?;
+        let mut seat = table.seats.get_unchecked(index);
+        if seat.stuck {
+            return Err(Error::AlreadyStuck);
+        }
+        seat.stuck = true;
+        table.seats.set(index, seat);
+
+        record_actions(&env, session_id, vec![&env, plain_action(ActionKind::Stick, Some(player), 1)]);
+
+        // Every action restarts the clock for the seats still to act
+        table.deadline = env.ledger().sequence() + table.timeout_ledgers;
+        env.storage().temporary().set(&key, &table);
+
+        Ok(())
+    }
+
+    /// Rank the table and submit the ranking to the GameHub.
+    /// Can only be called once every seat has stuck or busted.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - Rank of each seat in seat order (1 = best; ties share a rank)
+    pub fn reveal_table(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        let key = DataKey::Table(session_id);
+        let mut table: Table = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Already revealed - return the stored ranking
+        if let Some(ranks) = table.ranks {
+            return Ok(ranks);
+        }
+        if table.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if table.seats.iter().any(|seat| !seat.stuck) {
+            return Err(Error::SeatsNotFinished);
+        }
+
+        Self::settle_table(&env, session_id, &mut table)
+    }
+
+    /// Settle a table whose deadline has passed with seats still to stick.
+    /// Every idle seat forfeits and is ranked below all other seats; the rest
+    /// are ranked as in `reveal_table`. Once every seat has stuck or busted,
+    /// use `reveal_table` instead. Anyone may call this.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - Rank of each seat in seat order (1 = best; ties share a rank)
+    pub fn claim_table_timeout(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        let key = DataKey::Table(session_id);
+        let mut table: Table = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if table.ranks.is_some() || table.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if env.ledger().sequence() <= table.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        if table.seats.iter().all(|seat| seat.stuck) {
+            return Err(Error::NoIdlePlayer);
+        }
+
+        for i in 0..table.seats.len() {
+            let mut seat = table.seats.get_unchecked(i);
+            if !seat.stuck {
+                seat.forfeited = true;
+                seat.stuck = true;
+                table.seats.set(i, seat);
+            }
+        }
+
+        Self::settle_table(&env, session_id, &mut table)
+    }
+
+    /// Concede a seat at a table. The seat forfeits and is ranked below every
+    /// other seat; the rest of the table plays on. If only one seat is left
+    /// in the hand, it wins and the table is settled at once.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    /// * `player` - Address of the resigning player
+    ///
+    /// # Returns
+    /// * `Option<Vec<u32>>` - The ranking, if the resignation settled the table
+    pub fn table_resign(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<Option<Vec<u32>>, Error> {
+        player.require_auth();
+
+        let key = DataKey::Table(session_id);
+        let mut table: Table = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        if table.ranks.is_some() || table.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let index = Self::seat_index(&table, &player)?;
+        let mut seat = table.seats.get_unchecked(index);
+        if seat.forfeited {
+            return Err(Error::AlreadyStuck);
+        }
+        seat.forfeited = true;
+        seat.stuck = true;
+        table.seats.set(index, seat);
+
+        if table.seats.iter().filter(|seat| !seat.forfeited).count() <= 1 {
+            return Self::settle_table(&env, session_id, &mut table).map(Some);
+        }
+
+        // Every action restarts the clock for the seats still to act
+        table.deadline = env.ledger().sequence() + table.timeout_ledgers;
+        env.storage().temporary().set(&key, &table);
+
+        Ok(None)
+    }
+
+    /// Cancel a table by agreement. Every seated player must sign; the session
+    /// is cancelled through the Game Hub with no result and all points refunded.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    pub fn table_abort(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Table(session_id);
+        let mut table: Table = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        for seat in table.seats.iter() {
+            seat.player.require_auth();
+        }
+
+        if table.ranks.is_some() || table.aborted {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Call GameHub FIRST (before marking the table aborted)
+        Self::cancel_game_with_hub(&env, session_id)?;
+        table.aborted = true;
+        env.storage().temporary().set(&key, &table);
+
+        Ok(())
+    }
+
+    /// Get table information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the table
+    ///
+    /// # Returns
+    /// * `Table` - The table state (includes ranks after the table is revealed)
+    pub fn get_table(env: Env, session_id: u32) -> Result<Table, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Table(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
     // ========================================================================
     // Internal Helper Functions
     // ========================================================================
 
+    /// Require a player's consent to their stake (and side bets, if any)
+    fn require_stake_auth(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        points: i128,
+        side_bets: &Vec<SideBet>,
+    ) {
+        let mut args = vec![env, session_id.into_val(env), points.into_val(env)];
+        if !side_bets.is_empty() {
+            args.push_back(side_bets.into_val(env));
+        }
+        player.require_auth_for_args(args);
+    }
+
+    /// Rank a finished table, submit the ranking to the GameHub and store it
+    fn settle_table(env: &Env, session_id: u32, table: &mut Table) -> Result<Vec<u32>, Error> {
+        let ranks = rank_seats(&table.seats)?;
+        record_actions(env, session_id, vec![env, plain_action(ActionKind::Reveal, None, 1)]);
+
+        // Call GameHub FIRST (before storing the ranking)
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        game_hub.end_table_game(&session_id, &ranks);
+
+        table.ranks = Some(ranks.clone());
+        env.storage().temporary().set(&DataKey::Table(session_id), table);
+
+        Ok(ranks)
+    }
+
+    /// Find the seat index of a player at a table
+    fn seat_index(table: &Table, player: &Address) -> Result<u32, Error> {
+        table
+            .seats
+            .iter()
+            .position(|seat| seat.player == *player)
+            .map(|i| i as u32)
+            .ok_or(Error::NotPlayer)
+    }
+
+    /// Score a finished round, record it, and either end the match or deal the
+    /// next round. The caller is responsible for storing the updated game.
+    fn finish_round(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+        result: RoundResult,
+    ) -> Result<(), Error> {
+        // Record the round before the hands are replaced
+        let history_key = DataKey::RoundHistory(session_id);
+        let mut history: Vec<RoundRecord> = env
+            .storage()
+            .temporary()
+            .get(&history_key)
+            .unwrap_or(Vec::new(env));
+        history.push_back(RoundRecord {
+            round: game.round,
+            player1_hand: game.player1_hand.clone(),
+            player2_hand: game.player2_hand.clone(),
+            player1_value: calculate_hand_value(&game.player1_hand)?,
+            player2_value: calculate_hand_value(&game.player2_hand)?,
+            result,
+        });
+        env.storage().temporary().set(&history_key, &history);
+        env.storage()
+            .temporary()
+            .extend_ttl(&history_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Update the running score
+        match result {
+            RoundResult::Player1Won => game.player1_score += 1,
+            RoundResult::Player2Won => game.player2_score += 1,
+            RoundResult::Draw => {}
+        }
+
+        // Decide whether the match is over: a player reached the target, or
+        // the round limit was hit and the match is settled on score
+        let match_over =
+            game.player1_score >= game.match_target || game.player2_score >= game.match_target;
+        if match_over || game.round >= game.max_rounds {
+            if game.player1_score == game.player2_score {
+                // Call GameHub FIRST (before marking the game drawn)
+                Self::end_game_draw_with_hub(env, session_id)?;
+                game.drawn = true;
+            } else {
+                // Call GameHub FIRST (before setting winner)
+                let player1_won = game.player1_score > game.player2_score;
+                Self::end_game_with_hub(env, session_id, player1_won)?;
+
+                // Only set winner AFTER GameHub succeeds
+                game.winner = Some(if player1_won {
+                    game.player1.clone()
+                } else {
+                    game.player2.clone()
+                });
+            }
+            return Ok(());
+        }
+
+        // Deal new hands and continue with the next round
+        game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
+
+        // Reset stuck flags
+        game.player1_stuck = false;
+        game.player2_stuck = false;
+
+        // Deal new hands (2 cards each) from the round-indexed seed
+        let base_seed = round_seed(env, session_id, game);
+        Self::deal_round(env, session_id, game, &base_seed);
+
+        Ok(())
+    }
+
+    /// Deal both opening hands for the current round from `base_seed` and
+    /// log the deal
+    fn deal_round(env: &Env, session_id: u32, game: &mut Game, base_seed: &BytesN<32>) {
+        game.player1_hand = deal_opening_hand(env, base_seed, 1);
+        game.player2_hand = deal_opening_hand(env, base_seed, 2);
+
+        let mut actions = Vec::new(env);
+        push_deal_actions(&mut actions, &game.player1, &game.player1_hand, game.round);
+        push_deal_actions(&mut actions, &game.player2, &game.player2_hand, game.round);
+        record_actions(env, session_id, actions);
+    }
+
+    /// Settle a game's pending side bets through the Game Hub and store the
+    /// results. Once the cards are dealt the bets are judged on the opening
+    /// hands; before that, the bets of `forfeited` (a player who timed out or
+    /// resigned) are lost and every other bet is void.
+    fn settle_side_bets(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        forfeited: Option<&Address>,
+    ) -> Result<(), Error> {
+        let pending_key = DataKey::PendingSideBets(session_id);
+        let Some(side_bets) = env
+            .storage()
+            .temporary()
+            .get::<_, Vec<SideBet>>(&pending_key)
+        else {
+            return Ok(());
+        };
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        let dealt = !game.player1_hand.is_empty();
+        let mut results: Vec<SideBetResult> = Vec::new(env);
+        for bet in side_bets.iter() {
+            let payout = if !dealt {
+                if forfeited == Some(&bet.player) {
+                    0
+                } else {
+                    bet.wager
+                }
+            } else if bet.player == game.player1 {
+                side_bet_payout(bet.kind, bet.wager, &game.player1_hand, &game.player2_hand)?
+            } else {
+                side_bet_payout(bet.kind, bet.wager, &game.player2_hand, &game.player1_hand)?
+            };
+            game_hub.settle_side_bet(&session_id, &bet.player, &bet.wager, &payout);
+            results.push_back(SideBetResult {
+                player: bet.player,
+                kind: bet.kind,
+                wager: bet.wager,
+                payout,
+            });
+        }
+
+        env.storage().temporary().remove(&pending_key);
+        let side_bets_key = DataKey::SideBets(session_id);
+        env.storage().temporary().set(&side_bets_key, &results);
+        env.storage()
+            .temporary()
+            .extend_ttl(&side_bets_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
     /// Helper to end game with the Game Hub
     fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
         // Get GameHub address
@@ -572,6 +1889,38 @@ impl TwentyOneContract {
         Ok(())
     }
 
+    /// Helper to settle a drawn game with the Game Hub
+    fn end_game_draw_with_hub(env: &Env, session_id: u32) -> Result<(), Error> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        // Neither player wins; the Game Hub releases both players' points
+        game_hub.end_game_draw(&session_id);
+
+        Ok(())
+    }
+
+    /// Helper to cancel a game with the Game Hub
+    fn cancel_game_with_hub(env: &Env, session_id: u32) -> Result<(), Error> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        // No result is recorded; the Game Hub refunds both players' points
+        game_hub.cancel_game(&session_id);
+
+        Ok(())
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
@@ -630,6 +1979,115 @@ impl TwentyOneContract {
             .set(&DataKey::GameHubAddress, &new_hub);
     }
 
+    /// Get the number of rounds new games may deal before the match is settled on score
+    ///
+    /// # Returns
+    /// * `u32` - The configured round limit
+    pub fn get_max_rounds(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxRounds)
+            .unwrap_or(DEFAULT_MAX_ROUNDS)
+    }
+
+    /// Set the number of rounds new games may deal before the match is settled on score.
+    /// Games already in progress keep the limit they started with.
+    ///
+    /// # Arguments
+    /// * `max_rounds` - Round limit (must be at least 1 and at least the match target)
+    pub fn set_max_rounds(env: Env, max_rounds: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        // A limit below the match target would settle every match on score
+        if max_rounds == 0 || max_rounds < Self::get_match_target(env.clone()) {
+            return Err(Error::InvalidMaxRounds);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxRounds, &max_rounds);
+
+        Ok(())
+    }
+
+    /// Get the number of round wins new games need to win the match
+    ///
+    /// # Returns
+    /// * `u32` - The configured match target
+    pub fn get_match_target(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MatchTarget)
+            .unwrap_or(DEFAULT_MATCH_TARGET)
+    }
+
+    /// Set the number of round wins new games need to win the match
+    /// (e.g. 2 for best-of-3). Games already in progress keep their target.
+    /// To raise the target above the round limit, raise the limit first.
+    ///
+    /// # Arguments
+    /// * `match_target` - Round wins needed (must be at least 1 and at most the round limit)
+    pub fn set_match_target(env: Env, match_target: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        // A target above the round limit could never be reached
+        if match_target == 0 || match_target > Self::get_max_rounds(env.clone()) {
+            return Err(Error::InvalidMatchTarget);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MatchTarget, &match_target);
+
+        Ok(())
+    }
+
+    /// Get the number of ledgers players in new games have to act before the
+    /// opponent can claim a timeout
+    ///
+    /// # Returns
+    /// * `u32` - The configured timeout
+    pub fn get_timeout_ledgers(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS)
+    }
+
+    /// Set the number of ledgers players in new games have to act before the
+    /// opponent can claim a timeout. Games already in progress keep their timeout.
+    ///
+    /// # Arguments
+    /// * `timeout_ledgers` - Ledgers allowed per action, 1 to `MAX_TIMEOUT_LEDGERS`
+    pub fn set_timeout_ledgers(env: Env, timeout_ledgers: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if timeout_ledgers == 0 || timeout_ledgers > MAX_TIMEOUT_LEDGERS {
+            return Err(Error::InvalidTimeout);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TimeoutLedgers, &timeout_ledgers);
+
+        Ok(())
+    }
+
     /// Update the contract WASM hash (upgrade contract)
     ///
     /// # Arguments
@@ -7,9 +7,13 @@
 // For full integration tests with the real GameHub contract, see:
 // contracts/game_hub/src/tests/twenty_one_integration.rs
 
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
+use crate::{
+    deal_opening_hand, round_seed, shuffled_seed, side_bet_payout, Action, ActionKind, Error,
+    RoundResult, SideBet, SideBetKind, TwentyOneContract, TwentyOneContractClient,
+    MAX_TIMEOUT_LEDGERS,
+};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -36,6 +40,38 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn cancel_game(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn start_table_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _players: Vec<Address>,
+        _points: Vec<i128>,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_table_game(_env: Env, _session_id: u32, _ranks: Vec<u32>) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn settle_side_bet(
+        _env: Env,
+        _session_id: u32,
+        _player: Address,
+        _wager: i128,
+        _payout: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -151,6 +187,25 @@ fn calculate_hand_value_helper(hand: &Bytes) -> u32 {
     total
 }
 
+/// Start games on successive session IDs until the opening hands tie.
+/// Dealing is deterministic, so this always finds the same session.
+fn start_tied_game(
+    client: &TwentyOneContractClient<'static>,
+    player1: &Address,
+    player2: &Address,
+) -> u32 {
+    for session_id in 1000u32..1200 {
+        client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+        let game = client.get_game(&session_id);
+        if calculate_hand_value_helper(&game.player1_hand)
+            == calculate_hand_value_helper(&game.player2_hand)
+        {
+            return session_id;
+        }
+    }
+    panic!("No tied opening hands found");
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
@@ -163,7 +218,7 @@ fn test_complete_game_simple() {
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Vec::new(&client.env));
 
     // Get game to verify initial state
     let game = client.get_game(&session_id);
@@ -174,21 +229,27 @@ fn test_complete_game_simple() {
     assert_eq!(game.player2_points, points);
     assert_eq!(game.player1_hand.len(), 2); // 2 cards dealt
     assert_eq!(game.player2_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player1_stuck, false);
-    assert_eq!(game.player2_stuck, false);
+    assert!(!game.player1_stuck);
+    assert!(!game.player2_stuck);
+    assert_eq!(game.round, 1);
+    assert!(!game.drawn);
 
     // Both players stick immediately (no hits)
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
     // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
-
-    // Verify game is ended
+    let result = client.reveal_winner(&session_id);
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    match result {
+        RoundResult::Player1Won => assert_eq!(final_game.winner, Some(player1)),
+        RoundResult::Player2Won => assert_eq!(final_game.winner, Some(player2)),
+        RoundResult::Draw => {
+            // Tied hands are redealt instead of ending the game
+            assert!(final_game.winner.is_none());
+            assert_eq!(final_game.round, 2);
+        }
+    }
 }
 
 #[test]
@@ -196,7 +257,7 @@ fn test_initial_cards_dealt() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     let game = client.get_game(&session_id);
 
@@ -207,11 +268,11 @@ fn test_initial_cards_dealt() {
     // Cards should be in valid range (1-13)
     for i in 0..game.player1_hand.len() {
         let card = game.player1_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
     for i in 0..game.player2_hand.len() {
         let card = game.player2_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
 }
 
@@ -220,15 +281,15 @@ fn test_get_hand_value() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Get hand values
     let player1_value = client.get_hand_value(&session_id, &player1);
     let player2_value = client.get_hand_value(&session_id, &player2);
 
     // Hand values should be reasonable (2-20 for 2 cards, since max is 10 per card)
-    assert!(player1_value >= 2 && player1_value <= 20);
-    assert!(player2_value >= 2 && player2_value <= 20);
+    assert!((2..=20).contains(&player1_value));
+    assert!((2..=20).contains(&player2_value));
 
     // Verify hand value matches calculation
     let game = client.get_game(&session_id);
@@ -244,7 +305,7 @@ fn test_hit_adds_card() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
@@ -261,7 +322,7 @@ fn test_stick_prevents_further_hits() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -276,7 +337,7 @@ fn test_multiple_hits_allowed() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
@@ -308,26 +369,29 @@ fn test_closer_to_21_wins() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    let winner = client.reveal_winner(&session_id);
-
-    // Get final hand values
+    // Get hand values before reveal (a draw would redeal them)
     let game = client.get_game(&session_id);
     let player1_value = calculate_hand_value_helper(&game.player1_hand);
     let player2_value = calculate_hand_value_helper(&game.player2_hand);
 
+    let result = client.reveal_winner(&session_id);
+
     // Winner should be closer to 21
     if player1_value > player2_value {
-        assert_eq!(winner, player1);
+        assert_eq!(result, RoundResult::Player1Won);
+        assert_eq!(client.get_game(&session_id).winner, Some(player1));
     } else if player2_value > player1_value {
-        assert_eq!(winner, player2);
+        assert_eq!(result, RoundResult::Player2Won);
+        assert_eq!(client.get_game(&session_id).winner, Some(player2));
+    } else {
+        assert_eq!(result, RoundResult::Draw);
     }
-    // If equal, test will continue (draw handling)
 }
 
 #[test]
@@ -335,7 +399,7 @@ fn test_reveal_winner_requires_both_stuck() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Only player1 sticks
     client.stick(&session_id, &player1);
@@ -354,7 +418,7 @@ fn test_bust_detection() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Keep hitting until player1 busts
     // Note: With enough hits, player will eventually bust (hand value > 21)
@@ -365,9 +429,9 @@ fn test_bust_detection() {
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             busted = true;
-            assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
+            assert_eq!(winner, player2, "Player 2 should win when player 1 busts");
 
             // After a bust, subsequent operations should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -384,7 +448,7 @@ fn test_cannot_hit_after_bust() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Hit until bust (game ends)
     let mut did_bust = false;
@@ -393,9 +457,9 @@ fn test_cannot_hit_after_bust() {
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             did_bust = true;
-            assert_eq!(game.winner.unwrap(), player2);
+            assert_eq!(winner, player2);
 
             // Try to hit again after busting - should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -415,32 +479,668 @@ fn test_cannot_hit_after_bust() {
 fn test_draw_starts_new_round() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = start_tied_game(&client, &player1, &player2);
 
     // Both players stick immediately
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
+    // A tie is a successful result, not an error
+    let result = client.reveal_winner(&session_id);
+    assert_eq!(result, RoundResult::Draw);
+
+    // Verify new round was created and persisted
+    let game_after = client.get_game(&session_id);
+    assert_eq!(game_after.round, 2); // Round should increment
+    assert!(!game_after.drawn); // Game continues
+    assert!(game_after.winner.is_none());
+    assert!(!game_after.player1_stuck); // Flags reset
+    assert!(!game_after.player2_stuck);
+    assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
+    assert_eq!(game_after.player2_hand.len(), 2);
+
+    // Players can act again in the new round
+    client.stick(&session_id, &player1);
+}
+
+#[test]
+fn test_draw_settles_after_max_rounds() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    // With a single round allowed, the first tie ends the game
+    client.set_max_rounds(&1);
+
+    let session_id = start_tied_game(&client, &player1, &player2);
+    assert_eq!(client.get_game(&session_id).max_rounds, 1);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    let result = client.reveal_winner(&session_id);
+    assert_eq!(result, RoundResult::Draw);
+
     let game = client.get_game(&session_id);
-    let player1_value = calculate_hand_value_helper(&game.player1_hand);
-    let player2_value = calculate_hand_value_helper(&game.player2_hand);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    assert_eq!(game.round, 1); // No redeal
+
+    // Game is over: further actions fail, reveal stays idempotent
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_stick(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(client.reveal_winner(&session_id), RoundResult::Draw);
+}
+
+#[test]
+fn test_max_rounds_snapshot_at_start() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    assert_eq!(client.get_max_rounds(), 5);
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+
+    // Changing the limit only affects new games
+    client.set_max_rounds(&2);
+    assert_eq!(client.get_max_rounds(), 2);
+    assert_eq!(client.get_game(&session_id).max_rounds, 5);
+}
+
+#[test]
+fn test_cannot_set_zero_max_rounds() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    let result = client.try_set_max_rounds(&0);
+    assert_twenty_one_error(&result, Error::InvalidMaxRounds);
+}
+
+// ============================================================================
+// Match Tests
+// ============================================================================
+
+#[test]
+fn test_round_history_records_single_hand() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+    assert_eq!(client.get_round_history(&session_id).len(), 0);
+
+    let game = client.get_game(&session_id);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    let result = client.reveal_winner(&session_id);
+
+    let history = client.get_round_history(&session_id);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.round, 1);
+    assert_eq!(record.player1_hand, game.player1_hand);
+    assert_eq!(record.player2_hand, game.player2_hand);
+    assert_eq!(record.player1_value, calculate_hand_value_helper(&game.player1_hand));
+    assert_eq!(record.player2_value, calculate_hand_value_helper(&game.player2_hand));
+    assert_eq!(record.result, result);
+}
+
+#[test]
+fn test_best_of_three_match() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    client.set_match_target(&2);
+    client.set_max_rounds(&20);
+
+    let session_id = 32u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+    assert_eq!(client.get_game(&session_id).match_target, 2);
+
+    // Play rounds until one player reaches the target
+    let mut rounds = 0u32;
+    while client.get_game(&session_id).winner.is_none() {
+        let game = client.get_game(&session_id);
+        assert!(!game.drawn);
+        assert_eq!(game.round, rounds + 1);
+        assert_eq!(game.player1_hand.len(), 2); // Fresh hand each round
+
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        client.reveal_winner(&session_id);
+        rounds += 1;
+    }
+
+    let game = client.get_game(&session_id);
+    let history = client.get_round_history(&session_id);
+    assert_eq!(history.len(), rounds);
+    assert!(rounds >= 2);
+
+    // Running score matches the recorded round results
+    let mut player1_score = 0;
+    let mut player2_score = 0;
+    for record in history.iter() {
+        match record.result {
+            RoundResult::Player1Won => player1_score += 1,
+            RoundResult::Player2Won => player2_score += 1,
+            RoundResult::Draw => {}
+        }
+    }
+    assert_eq!(game.player1_score, player1_score);
+    assert_eq!(game.player2_score, player2_score);
+
+    if game.winner == Some(player1.clone()) {
+        assert_eq!(game.player1_score, 2);
+        assert!(game.player2_score < 2);
+    } else {
+        assert_eq!(game.player2_score, 2);
+        assert!(game.player1_score < 2);
+    }
+}
+
+#[test]
+fn test_bust_in_match_starts_next_round() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    client.set_match_target(&2);
+
+    let session_id = 33u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+
+    // Hit until player1 busts the first round
+    while client.get_game(&session_id).round == 1 {
+        client.hit(&session_id, &player1);
+    }
+
+    // Player 2 took the round, but the match continues
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_none());
+    assert_eq!(game.round, 2);
+    assert_eq!(game.player1_score, 0);
+    assert_eq!(game.player2_score, 1);
+    assert_eq!(game.player1_hand.len(), 2);
+
+    let history = client.get_round_history(&session_id);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.result, RoundResult::Player2Won);
+    assert!(record.player1_value > 21);
+}
+
+#[test]
+fn test_match_settled_on_score_at_max_rounds() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    // Only a player who wins both rounds reaches the target
+    client.set_match_target(&2);
+    client.set_max_rounds(&2);
+
+    let session_id = 34u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+    for _ in 0..2 {
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        client.reveal_winner(&session_id);
+    }
+
+    // The match ends at the round limit whatever the score
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    if game.player1_score == game.player2_score {
+        assert!(game.drawn);
+    } else if game.player1_score > game.player2_score {
+        assert_eq!(game.winner, Some(player1));
+    } else {
+        assert_eq!(game.winner, Some(player2));
+    }
+}
+
+#[test]
+fn test_match_target_cannot_exceed_max_rounds() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    // The default limit is 5 rounds
+    let result = client.try_set_match_target(&6);
+    assert_twenty_one_error(&result, Error::InvalidMatchTarget);
+    client.set_match_target(&5);
+
+    let result = client.try_set_max_rounds(&4);
+    assert_twenty_one_error(&result, Error::InvalidMaxRounds);
+    client.set_max_rounds(&9);
+    client.set_match_target(&9);
+    assert_eq!(client.get_match_target(), 9);
+}
+
+#[test]
+fn test_cannot_set_zero_match_target() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    assert_eq!(client.get_match_target(), 1);
+    let result = client.try_set_match_target(&0);
+    assert_twenty_one_error(&result, Error::InvalidMatchTarget);
+}
+
+#[test]
+fn test_cannot_get_round_history_nonexistent_game() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    let result = client.try_get_round_history(&999);
+    assert_twenty_one_error(&result, Error::GameNotFound);
+}
+
+// ============================================================================
+// Side Bet Tests
+// ============================================================================
+
+fn entropy_commitment(env: &Env, entropy: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(entropy.clone())).into()
+}
+
+/// Have both players commit and then reveal their entropy, dealing a side bet game
+fn shuffle(
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    entropy1: &BytesN<32>,
+    entropy2: &BytesN<32>,
+) {
+    let env = &client.env;
+    client.commit_entropy(&session_id, player1, &entropy_commitment(env, entropy1));
+    client.commit_entropy(&session_id, player2, &entropy_commitment(env, entropy2));
+    client.reveal_entropy(&session_id, player1, entropy1);
+    client.reveal_entropy(&session_id, player2, entropy2);
+}
+
+#[test]
+fn test_side_bets_settled_at_deal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 50u32;
+    let side_bets = vec![
+        &env,
+        SideBet {
+            player: player1.clone(),
+            kind: SideBetKind::PerfectPairs,
+            wager: 10,
+        },
+        SideBet {
+            player: player1.clone(),
+            kind: SideBetKind::TwentyOnePlusThree,
+            wager: 5,
+        },
+        SideBet {
+            player: player2.clone(),
+            kind: SideBetKind::Insurance,
+            wager: 20,
+        },
+    ];
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &side_bets);
+    shuffle(
+        &client,
+        session_id,
+        &player1,
+        &player2,
+        &BytesN::from_array(&env, &[1; 32]),
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+
+    let game = client.get_game(&session_id);
+    let results = client.get_side_bets(&session_id);
+    assert_eq!(results.len(), 3);
+    for (bet, result) in side_bets.iter().zip(results.iter()) {
+        assert_eq!(result.player, bet.player);
+        assert_eq!(result.kind, bet.kind);
+        assert_eq!(result.wager, bet.wager);
+    }
+
+    // Payouts follow the payout tables applied to the opening hands
+    let p1 = results.get(0).unwrap();
+    assert_eq!(
+        p1.payout,
+        side_bet_payout(p1.kind, p1.wager, &game.player1_hand, &game.player2_hand).unwrap()
+    );
+    let p2 = results.get(2).unwrap();
+    assert_eq!(
+        p2.payout,
+        side_bet_payout(p2.kind, p2.wager, &game.player2_hand, &game.player1_hand).unwrap()
+    );
+
+    // The main hand is unaffected
+    assert_eq!(game.player1_points, 100_0000000);
+    assert!(game.winner.is_none());
+}
+
+#[test]
+fn test_side_bet_deal_waits_for_both_reveals() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 55u32;
+    let bets = vec![
+        &env,
+        SideBet {
+            player: player1.clone(),
+            kind: SideBetKind::PerfectPairs,
+            wager: 10,
+        },
+    ];
+    client.start_game(&session_id, &player1, &player2, &100, &100, &bets);
+
+    // Nothing is dealt or settled at start
+    let game = client.get_game(&session_id);
+    assert!(game.player1_hand.is_empty() && game.player2_hand.is_empty());
+    assert_eq!(client.get_side_bets(&session_id).len(), 0);
+    assert_twenty_one_error(&client.try_hit(&session_id, &player1), Error::CardsNotDealt);
+    assert_twenty_one_error(&client.try_stick(&session_id, &player2), Error::CardsNotDealt);
+
+    // No one reveals before both have committed
+    let entropy1 = BytesN::from_array(&env, &[3; 32]);
+    let entropy2 = BytesN::from_array(&env, &[4; 32]);
+    client.commit_entropy(&session_id, &player1, &entropy_commitment(&env, &entropy1));
+    let result = client.try_commit_entropy(&session_id, &player1, &entropy_commitment(&env, &entropy1));
+    assert_twenty_one_error(&result, Error::AlreadyCommitted);
+    let result = client.try_reveal_entropy(&session_id, &player1, &entropy1);
+    assert_twenty_one_error(&result, Error::OpponentNotCommitted);
+    client.commit_entropy(&session_id, &player2, &entropy_commitment(&env, &entropy2));
+
+    // A reveal must match the commitment
+    let result = client.try_reveal_entropy(&session_id, &player1, &entropy2);
+    assert_twenty_one_error(&result, Error::InvalidEntropy);
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+    assert!(client.get_game(&session_id).player1_hand.is_empty());
+    assert_eq!(client.get_side_bets(&session_id).len(), 0);
+
+    // The second reveal deals from a seed mixing both entropies
+    client.reveal_entropy(&session_id, &player2, &entropy2);
+    let game = client.get_game(&session_id);
+    env.as_contract(&client.address, || {
+        let base_seed = round_seed(&env, session_id, &game);
+        let seed = shuffled_seed(&env, &base_seed, &entropy1, &entropy2);
+        assert_eq!(game.player1_hand, deal_opening_hand(&env, &seed, 1));
+        assert_eq!(game.player2_hand, deal_opening_hand(&env, &seed, 2));
+    });
+    assert_eq!(client.get_side_bets(&session_id).len(), 1);
+
+    let result = client.try_commit_entropy(&session_id, &player1, &entropy_commitment(&env, &entropy1));
+    assert_twenty_one_error(&result, Error::CardsAlreadyDealt);
+    client.hit(&session_id, &player1);
+}
+
+#[test]
+fn test_bettor_cannot_precompute_side_bets() {
+    // Player 1 picks the session and their own entropy; the opening hands
+    // still depend on entropy player 2 only reveals after both committed
+    let deal = |entropy2: [u8; 32]| {
+        let (env, client, _hub, player1, player2) = setup_test();
+        let bets = vec![
+            &env,
+            SideBet {
+                player: player1.clone(),
+                kind: SideBetKind::PerfectPairs,
+                wager: 10,
+            },
+        ];
+        client.start_game(&56, &player1, &player2, &100, &100, &bets);
+        let entropy1 = BytesN::from_array(&env, &[5; 32]);
+        let entropy2 = BytesN::from_array(&env, &entropy2);
+        shuffle(&client, 56, &player1, &player2, &entropy1, &entropy2);
+        let game = client.get_game(&56);
+        let (hand1, hand2) = (game.player1_hand, game.player2_hand);
+        [hand1.get_unchecked(0), hand1.get_unchecked(1), hand2.get_unchecked(0), hand2.get_unchecked(1)]
+    };
+
+    let first = deal([0; 32]);
+    assert!((1u8..4).any(|b| deal([b; 32]) != first));
+}
+
+#[test]
+fn test_timeout_before_deal_forfeits_side_bets() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 57u32;
+    let bets = vec![
+        &env,
+        SideBet {
+            player: player1.clone(),
+            kind: SideBetKind::PerfectPairs,
+            wager: 10,
+        },
+        SideBet {
+            player: player2.clone(),
+            kind: SideBetKind::Insurance,
+            wager: 20,
+        },
+    ];
+    client.start_game(&session_id, &player1, &player2, &100, &100, &bets);
+
+    // Player 2 waits to see player 1's entropy and never reveals
+    let entropy1 = BytesN::from_array(&env, &[6; 32]);
+    let entropy2 = BytesN::from_array(&env, &[7; 32]);
+    client.commit_entropy(&session_id, &player1, &entropy_commitment(&env, &entropy1));
+    client.commit_entropy(&session_id, &player2, &entropy_commitment(&env, &entropy2));
+    client.reveal_entropy(&session_id, &player1, &entropy1);
+
+    advance_ledgers(&env, client.get_timeout_ledgers() + 1);
+    assert_eq!(client.claim_timeout(&session_id), RoundResult::Player1Won);
+
+    // Player 1's bet is void, player 2's is lost
+    let results = client.get_side_bets(&session_id);
+    assert_eq!(results.get(0).unwrap().payout, 10);
+    assert_eq!(results.get(1).unwrap().payout, 0);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1));
+}
+
+#[test]
+fn test_no_side_bets_recorded_by_default() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 51u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+    assert_eq!(client.get_side_bets(&session_id).len(), 0);
+}
+
+#[test]
+fn test_invalid_side_bets_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    // Wager must be positive
+    let bets = vec![
+        &env,
+        SideBet {
+            player: player1.clone(),
+            kind: SideBetKind::PerfectPairs,
+            wager: 0,
+        },
+    ];
+    let result = client.try_start_game(&52, &player1, &player2, &100, &100, &bets);
+    assert_twenty_one_error(&result, Error::InvalidSideBet);
+
+    // Only one bet of each kind per player
+    let bet = SideBet {
+        player: player2.clone(),
+        kind: SideBetKind::Insurance,
+        wager: 1,
+    };
+    let bets = vec![&env, bet.clone(), bet];
+    let result = client.try_start_game(&53, &player1, &player2, &100, &100, &bets);
+    assert_twenty_one_error(&result, Error::InvalidSideBet);
+
+    // Bets can only be placed by the game's players
+    let bets = vec![
+        &env,
+        SideBet {
+            player: Address::generate(&env),
+            kind: SideBetKind::PerfectPairs,
+            wager: 1,
+        },
+    ];
+    let result = client.try_start_game(&54, &player1, &player2, &100, &100, &bets);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_side_bet_payout_tables() {
+    let env = Env::default();
+    let hand = |a: u8, b: u8| Bytes::from_array(&env, &[a, b]);
+
+    // Perfect pairs: same rank pays 6:1
+    assert_eq!(side_bet_payout(SideBetKind::PerfectPairs, 10, &hand(7, 7), &hand(2, 3)), Ok(70));
+    assert_eq!(side_bet_payout(SideBetKind::PerfectPairs, 10, &hand(7, 8), &hand(2, 3)), Ok(0));
+
+    // 21+3: trips pay 30:1, straights 10:1 (Ace low or high)
+    let bet = SideBetKind::TwentyOnePlusThree;
+    assert_eq!(side_bet_payout(bet, 10, &hand(9, 9), &hand(9, 3)), Ok(310));
+    assert_eq!(side_bet_payout(bet, 10, &hand(5, 3), &hand(4, 13)), Ok(110));
+    assert_eq!(side_bet_payout(bet, 10, &hand(1, 2), &hand(3, 13)), Ok(110));
+    assert_eq!(side_bet_payout(bet, 10, &hand(13, 1), &hand(12, 5)), Ok(110));
+    assert_eq!(side_bet_payout(bet, 10, &hand(12, 13), &hand(2, 5)), Ok(0));
+
+    // Insurance: void unless the opponent shows an Ace, pays 2:1 on a ten-value hole card
+    let bet = SideBetKind::Insurance;
+    assert_eq!(side_bet_payout(bet, 10, &hand(2, 3), &hand(5, 13)), Ok(10));
+    assert_eq!(side_bet_payout(bet, 10, &hand(2, 3), &hand(1, 12)), Ok(30));
+    assert_eq!(side_bet_payout(bet, 10, &hand(2, 3), &hand(1, 9)), Ok(0));
+}
+
+// ============================================================================
+// Table (Multi-Seat) Tests
+// ============================================================================
 
-    // Only test draw behavior if hands are equal
-    if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
+/// Generate `count` distinct players with equal stakes
+fn table_players(env: &Env, count: u32) -> (Vec<Address>, Vec<i128>) {
+    let mut players = Vec::new(env);
+    let mut points = Vec::new(env);
+    for _ in 0..count {
+        players.push_back(Address::generate(env));
+        points.push_back(10_0000000i128);
+    }
+    (players, points)
+}
 
-        // Verify new round was created
-        let game_after = client.get_game(&session_id);
-        assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
-        assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
-        assert_eq!(game_after.player2_hand.len(), 2);
+#[test]
+fn test_table_deals_every_seat() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 6);
+
+    let session_id = 40u32;
+    client.start_table(&session_id, &players, &points);
+
+    let table = client.get_table(&session_id);
+    assert_eq!(table.seats.len(), 6);
+    assert!(table.ranks.is_none());
+    for (i, seat) in table.seats.iter().enumerate() {
+        assert_eq!(seat.player, players.get(i as u32).unwrap());
+        assert_eq!(seat.points, 10_0000000);
+        assert_eq!(seat.hand.len(), 2);
+        assert!(!seat.stuck);
+        assert!(!seat.busted);
     }
-    // If not a draw, test passes (no assertion needed)
+}
+
+#[test]
+fn test_table_complete_hand_ranks_seats() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 4);
+
+    let session_id = 41u32;
+    client.start_table(&session_id, &players, &points);
+
+    // Seat 0 hits until bust, everyone else sticks
+    let bust_player = players.get(0).unwrap();
+    while !client.get_table(&session_id).seats.get(0).unwrap().busted {
+        client.table_hit(&session_id, &bust_player);
+    }
+    for player in players.iter().skip(1) {
+        client.table_stick(&session_id, &player);
+    }
+
+    let ranks = client.reveal_table(&session_id);
+    assert_eq!(ranks.len(), 4);
+
+    // Busted seat ranks below every standing seat
+    assert_eq!(ranks.get(0).unwrap(), 4);
+
+    // Standing seats are ranked by hand value
+    let table = client.get_table(&session_id);
+    assert_eq!(table.ranks, Some(ranks.clone()));
+    for i in 1..4u32 {
+        for j in 1..4u32 {
+            let value_i = calculate_hand_value_helper(&table.seats.get(i).unwrap().hand);
+            let value_j = calculate_hand_value_helper(&table.seats.get(j).unwrap().hand);
+            if value_i > value_j {
+                assert!(ranks.get(i).unwrap() < ranks.get(j).unwrap());
+            } else if value_i == value_j {
+                assert_eq!(ranks.get(i).unwrap(), ranks.get(j).unwrap());
+            }
+        }
+    }
+
+    // Reveal is idempotent and the hand is over
+    assert_eq!(client.reveal_table(&session_id), ranks);
+    let result = client.try_table_stick(&session_id, &players.get(1).unwrap());
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_table_reveal_requires_all_seats_finished() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 3);
+
+    let session_id = 42u32;
+    client.start_table(&session_id, &players, &points);
+    client.table_stick(&session_id, &players.get(0).unwrap());
+    client.table_stick(&session_id, &players.get(1).unwrap());
+
+    let result = client.try_reveal_table(&session_id);
+    assert_twenty_one_error(&result, Error::SeatsNotFinished);
+}
+
+#[test]
+fn test_table_seat_count_limits() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    let (players, points) = table_players(&env, 1);
+    let result = client.try_start_table(&43, &players, &points);
+    assert_twenty_one_error(&result, Error::InvalidSeatCount);
+
+    let (players, points) = table_players(&env, 7);
+    let result = client.try_start_table(&44, &players, &points);
+    assert_twenty_one_error(&result, Error::InvalidSeatCount);
+
+    // Stakes must be given for every seat
+    let (players, _points) = table_players(&env, 3);
+    let result = client.try_start_table(&45, &players, &vec![&env, 1i128, 1]);
+    assert_twenty_one_error(&result, Error::InvalidSeatCount);
+}
+
+#[test]
+fn test_table_rejects_duplicate_players() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let players = vec![&env, player1.clone(), player2, player1];
+    let points = vec![&env, 1i128, 1, 1];
+    let result = client.try_start_table(&46, &players, &points);
+    assert_twenty_one_error(&result, Error::DuplicatePlayer);
+}
+
+#[test]
+fn test_table_non_player_and_double_stick() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 2);
+
+    let session_id = 47u32;
+    client.start_table(&session_id, &players, &points);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_table_hit(&session_id, &non_player);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+
+    let player = players.get(0).unwrap();
+    client.table_stick(&session_id, &player);
+    let result = client.try_table_stick(&session_id, &player);
+    assert_twenty_one_error(&result, Error::AlreadyStuck);
+    let result = client.try_table_hit(&session_id, &player);
+    assert_twenty_one_error(&result, Error::AlreadyStuck);
+
+    let result = client.try_get_table(&999);
+    assert_twenty_one_error(&result, Error::GameNotFound);
 }
 
 // ============================================================================
@@ -452,7 +1152,7 @@ fn test_cannot_stick_twice() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // First stick succeeds
     client.stick(&session_id, &player1);
@@ -468,7 +1168,7 @@ fn test_non_player_cannot_hit() {
     let non_player = Address::generate(&env);
 
     let session_id = 13u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Non-player tries to hit
     let result = client.try_hit(&session_id, &non_player);
@@ -481,7 +1181,7 @@ fn test_non_player_cannot_stick() {
     let non_player = Address::generate(&env);
 
     let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Non-player tries to stick
     let result = client.try_stick(&session_id, &non_player);
@@ -494,7 +1194,7 @@ fn test_non_player_cannot_get_hand_value() {
     let non_player = Address::generate(&env);
 
     let session_id = 15u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Non-player tries to get hand value
     let result = client.try_get_hand_value(&session_id, &non_player);
@@ -538,15 +1238,15 @@ fn test_cannot_hit_after_game_ended() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 16u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Reveal winner (may panic if draw, but that's okay)
-    let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    // Reveal winner (a draw redeals instead of ending the game)
+    client.reveal_winner(&session_id);
+    if client.get_game(&session_id).winner.is_some() {
         // Game ended successfully
         // Try to hit after game ended
         let hit_result = client.try_hit(&session_id, &player1);
@@ -559,7 +1259,7 @@ fn test_cannot_stick_after_game_ended() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 17u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -568,8 +1268,8 @@ fn test_cannot_stick_after_game_ended() {
     client.stick(&session_id, &player2);
 
     // Reveal winner
-    let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    client.reveal_winner(&session_id);
+    if client.get_game(&session_id).winner.is_some() {
         // Game ended, verify stuck flag is set
         let game = client.get_game(&session_id);
         assert!(game.player1_stuck);
@@ -585,19 +1285,17 @@ fn test_reveal_winner_idempotent() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 18u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
     // First reveal
-    let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
-
+    let result1 = client.reveal_winner(&session_id);
+    if result1 != RoundResult::Draw {
         // Second reveal should return same winner (idempotent)
-        let winner2 = client.reveal_winner(&session_id);
-        assert_eq!(winner1, winner2);
+        let result2 = client.reveal_winner(&session_id);
+        assert_eq!(result1, result2);
     }
 }
 
@@ -615,8 +1313,8 @@ fn test_multiple_games_independent() {
     let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000, &Vec::new(&client.env));
 
     // Play both games independently (use try_ methods to handle potential busts)
     let _ = client.try_hit(&session1, &player1);
@@ -653,8 +1351,8 @@ fn test_multiple_sessions() {
     let session1 = 22u32;
     let session2 = 23u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000, &Vec::new(&client.env));
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -674,7 +1372,7 @@ fn test_asymmetric_points() {
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    client.start_game(&session_id, &player1, &player2, &points1, &points2, &Vec::new(&client.env));
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
@@ -683,8 +1381,8 @@ fn test_asymmetric_points() {
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Try to reveal winner (may be draw)
-    let _ = client.try_reveal_winner(&session_id);
+    // Reveal winner (may be draw)
+    client.reveal_winner(&session_id);
 }
 
 // ============================================================================
@@ -698,7 +1396,7 @@ fn test_face_cards_worth_10() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 25u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     let game = client.get_game(&session_id);
 
@@ -708,7 +1406,7 @@ fn test_face_cards_worth_10() {
         let expected_value = if card >= 10 { 10 } else { card };
 
         // Verify this matches our expectation
-        assert!(expected_value >= 1 && expected_value <= 10);
+        assert!((1..=10).contains(&expected_value));
     }
 }
 
@@ -717,7 +1415,7 @@ fn test_hand_value_calculation() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 26u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     // Get hand value from contract
     let contract_value = client.get_hand_value(&session_id, &player1);
@@ -729,6 +1427,267 @@ fn test_hand_value_calculation() {
     assert_eq!(contract_value, expected_value);
 }
 
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+/// Move the ledger sequence forward
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ledgers);
+}
+
+#[test]
+fn test_claim_timeout_awards_the_player_who_stuck() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100, &Vec::new(&env));
+    let timeout = client.get_timeout_ledgers();
+
+    // Sticking restarts the clock for player 2
+    advance_ledgers(&env, 100);
+    client.stick(&session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.deadline, env.ledger().sequence() + timeout);
+
+    advance_ledgers(&env, timeout);
+    let result = client.try_claim_timeout(&session_id);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    advance_ledgers(&env, 1);
+    assert_eq!(client.claim_timeout(&session_id), RoundResult::Player1Won);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1));
+
+    // The match is over
+    let result = client.try_stick(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_claim_timeout(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_draw_and_no_idle_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let timeout = client.get_timeout_ledgers();
+
+    // Neither player stuck: nobody is owed the win
+    client.start_game(&1, &player1, &player2, &100, &100, &Vec::new(&env));
+    advance_ledgers(&env, timeout + 1);
+    assert_eq!(client.claim_timeout(&1), RoundResult::Draw);
+    let game = client.get_game(&1);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    assert_eq!(client.reveal_winner(&1), RoundResult::Draw);
+
+    // Both players stuck: the round is revealed, not timed out
+    client.start_game(&2, &player1, &player2, &100, &100, &Vec::new(&env));
+    client.stick(&2, &player1);
+    client.stick(&2, &player2);
+    advance_ledgers(&env, timeout + 1);
+    let result = client.try_claim_timeout(&2);
+    assert_twenty_one_error(&result, Error::NoIdlePlayer);
+    client.reveal_winner(&2);
+}
+
+#[test]
+fn test_admin_sets_timeout_for_new_games() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &100, &Vec::new(&env));
+    let result = client.try_set_timeout_ledgers(&0);
+    assert_twenty_one_error(&result, Error::InvalidTimeout);
+    let result = client.try_set_timeout_ledgers(&(MAX_TIMEOUT_LEDGERS + 1));
+    assert_twenty_one_error(&result, Error::InvalidTimeout);
+    client.set_timeout_ledgers(&10);
+    assert_eq!(client.get_timeout_ledgers(), 10);
+
+    // Games in progress keep the timeout they started with
+    client.start_game(&2, &player1, &player2, &100, &100, &Vec::new(&env));
+    client.stick(&1, &player2);
+    client.stick(&2, &player2);
+    advance_ledgers(&env, 11);
+    let result = client.try_claim_timeout(&1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+    assert_eq!(client.claim_timeout(&2), RoundResult::Player2Won);
+}
+
+#[test]
+fn test_claim_table_timeout_ranks_idle_seats_last() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 4);
+    let timeout = client.get_timeout_ledgers();
+
+    let session_id = 50u32;
+    client.start_table(&session_id, &players, &points);
+
+    // Seat 0 busts, seat 1 sticks, seats 2 and 3 never act
+    let bust_player = players.get(0).unwrap();
+    while !client.get_table(&session_id).seats.get(0).unwrap().busted {
+        client.table_hit(&session_id, &bust_player);
+    }
+    advance_ledgers(&env, 100);
+    client.table_stick(&session_id, &players.get(1).unwrap());
+    assert_eq!(
+        client.get_table(&session_id).deadline,
+        env.ledger().sequence() + timeout
+    );
+
+    advance_ledgers(&env, timeout);
+    let result = client.try_claim_table_timeout(&session_id);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    advance_ledgers(&env, 1);
+    let ranks = client.claim_table_timeout(&session_id);
+    assert_eq!(ranks, vec![&env, 2u32, 1, 3, 3]);
+    let table = client.get_table(&session_id);
+    assert_eq!(table.ranks, Some(ranks.clone()));
+    assert!(!table.seats.get(1).unwrap().forfeited);
+    assert!(table.seats.get(2).unwrap().forfeited);
+    assert!(table.seats.get(3).unwrap().forfeited);
+
+    // The hand is over
+    let result = client.try_table_stick(&session_id, &players.get(2).unwrap());
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_claim_table_timeout(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(client.reveal_table(&session_id), ranks);
+}
+
+#[test]
+fn test_claim_table_timeout_needs_an_idle_seat() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 2);
+
+    let session_id = 51u32;
+    client.start_table(&session_id, &players, &points);
+    for player in players.iter() {
+        client.table_stick(&session_id, &player);
+    }
+    advance_ledgers(&env, client.get_timeout_ledgers() + 1);
+
+    // Every seat has stuck: the table is revealed, not timed out
+    let result = client.try_claim_table_timeout(&session_id);
+    assert_twenty_one_error(&result, Error::NoIdlePlayer);
+    client.reveal_table(&session_id);
+}
+
+// ============================================================================
+// Resign and Abort Tests
+// ============================================================================
+
+#[test]
+fn test_resign_ends_the_match_for_the_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &100, &Vec::new(&env));
+    client.stick(&1, &player2);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&1, &outsider);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+
+    assert_eq!(client.resign(&1, &player1), RoundResult::Player2Won);
+    assert_eq!(env.auths()[0].0, player1);
+    assert_eq!(client.get_game(&1).winner, Some(player2.clone()));
+    assert_eq!(client.reveal_winner(&1), RoundResult::Player2Won);
+
+    // The match is over
+    let result = client.try_hit(&1, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&1, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_abort_needs_both_players_and_ends_the_match() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &100, &Vec::new(&env));
+    client.stick(&1, &player1);
+
+    client.abort(&1);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2, "abort must be signed by both players");
+    assert_eq!(auths[0].0, player1);
+    assert_eq!(auths[1].0, player2);
+
+    let game = client.get_game(&1);
+    assert!(game.aborted);
+    assert!(game.winner.is_none());
+    assert!(!game.drawn);
+    let result = client.try_stick(&1, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&1, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_abort(&1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    advance_ledgers(&env, client.get_timeout_ledgers() + 1);
+    let result = client.try_claim_timeout(&1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_table_resign_ranks_the_seat_last() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 3);
+
+    let session_id = 52u32;
+    client.start_table(&session_id, &players, &points);
+
+    // The other two seats play on
+    let non_player = Address::generate(&env);
+    let result = client.try_table_resign(&session_id, &non_player);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+    assert_eq!(client.table_resign(&session_id, &players.get(1).unwrap()), None);
+    assert_eq!(env.auths()[0].0, players.get(1).unwrap());
+    let result = client.try_table_resign(&session_id, &players.get(1).unwrap());
+    assert_twenty_one_error(&result, Error::AlreadyStuck);
+    let result = client.try_table_hit(&session_id, &players.get(1).unwrap());
+    assert_twenty_one_error(&result, Error::AlreadyStuck);
+
+    // Once a single seat is left, it wins
+    let ranks = client.table_resign(&session_id, &players.get(0).unwrap()).unwrap();
+    assert_eq!(ranks, vec![&env, 2u32, 2, 1]);
+    assert_eq!(client.get_table(&session_id).ranks, Some(ranks));
+    let result = client.try_table_stick(&session_id, &players.get(2).unwrap());
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_table_abort_needs_every_seat() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 3);
+
+    let session_id = 53u32;
+    client.start_table(&session_id, &players, &points);
+    client.table_stick(&session_id, &players.get(0).unwrap());
+
+    client.table_abort(&session_id);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 3, "abort must be signed by every seat");
+    for (i, player) in players.iter().enumerate() {
+        assert_eq!(auths[i].0, player);
+    }
+
+    let table = client.get_table(&session_id);
+    assert!(table.aborted);
+    assert!(table.ranks.is_none());
+    let result = client.try_table_stick(&session_id, &players.get(1).unwrap());
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_table(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_table_resign(&session_id, &players.get(1).unwrap());
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_table_abort(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    advance_ledgers(&env, client.get_timeout_ledgers() + 1);
+    let result = client.try_claim_table_timeout(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
@@ -832,12 +1791,12 @@ fn test_deterministic_card_dealing() {
     let session_id = 27u32;
 
     // Start first game
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
     let game1 = client.get_game(&session_id);
 
     // Start second game with same session_id in new environment (should be identical)
     let (_env2, client2, _hub2, player1_2, player2_2) = setup_test();
-    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
+    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000, &Vec::new(&client2.env));
     let game2 = client2.get_game(&session_id);
 
     // Note: Since we generate new addresses each time, the cards will be different
@@ -851,12 +1810,164 @@ fn test_round_counter() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 28u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
 
     let game = client.get_game(&session_id);
     assert_eq!(game.round, 1); // First round
 }
 
+// ============================================================================
+// Action Log / Replay Tests
+// ============================================================================
+
+/// Recompute a logged card from the seed derivation documented in the contract
+fn replay_card(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    action: &Action,
+) -> u32 {
+    let player = action.player.clone().unwrap();
+    let mut seed_bytes = Bytes::new(env);
+    match action.kind {
+        ActionKind::Deal => {
+            let mut base = Bytes::new(env);
+            base.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+            base.append(&player1.to_string().to_bytes());
+            base.append(&player2.to_string().to_bytes());
+            base.append(&Bytes::from_array(env, &action.round.to_be_bytes()));
+            let base_seed = env.crypto().keccak256(&base);
+            let seat = if player == *player1 { 1 } else { 2 };
+            seed_bytes.append(&Bytes::from(base_seed));
+            seed_bytes.append(&Bytes::from_array(env, &[action.seed_index as u8, seat]));
+        }
+        ActionKind::Hit => {
+            seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+            seed_bytes.append(&player.to_string().to_bytes());
+            seed_bytes.append(&Bytes::from_array(env, &action.seed_index.to_be_bytes()));
+            seed_bytes.append(&Bytes::from_array(env, &action.round.to_be_bytes()));
+        }
+        _ => panic!("Action has no card"),
+    }
+    let seed = env.crypto().keccak256(&seed_bytes);
+    env.as_contract(&client.address, || {
+        env.prng().seed(seed.into());
+        env.prng().gen_range::<u64>(1..=13) as u32
+    })
+}
+
+#[test]
+fn test_action_log_records_every_action() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 60u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+
+    // Opening deal: 2 cards per player
+    let log = client.get_action_log(&session_id);
+    assert_eq!(log.len(), 4);
+    for action in log.iter() {
+        assert_eq!(action.kind, ActionKind::Deal);
+        assert_eq!(action.round, 1);
+    }
+
+    client.hit(&session_id, &player2);
+    let game = client.get_game(&session_id);
+    if game.winner.is_none() {
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        client.reveal_winner(&session_id);
+
+        let log = client.get_action_log(&session_id);
+        assert_eq!(log.len(), 8);
+        assert_eq!(log.get(4).unwrap().kind, ActionKind::Hit);
+        assert_eq!(log.get(5).unwrap().kind, ActionKind::Stick);
+        assert_eq!(log.get(6).unwrap().kind, ActionKind::Stick);
+        assert_eq!(log.get(7).unwrap().kind, ActionKind::Reveal);
+
+        let hit = log.get(4).unwrap();
+        assert_eq!(hit.player, Some(player2.clone()));
+        assert_eq!(hit.seed_index, 2);
+        assert_eq!(hit.card, Some(game.player2_hand.get(2).unwrap() as u32));
+        assert_eq!(log.get(7).unwrap().player, None);
+    }
+}
+
+#[test]
+fn test_action_log_replays_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 61u32;
+    client.set_match_target(&2);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Vec::new(&client.env));
+
+    // Play a few rounds with hits so the log spans several deals
+    while client.get_game(&session_id).winner.is_none() && !client.get_game(&session_id).drawn {
+        let round = client.get_game(&session_id).round;
+        client.hit(&session_id, &player1);
+        let game = client.get_game(&session_id);
+        if game.round != round || game.winner.is_some() || game.drawn {
+            continue; // Player 1 busted
+        }
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        client.reveal_winner(&session_id);
+    }
+
+    // Every logged card can be reproduced from the documented seeds
+    let log = client.get_action_log(&session_id);
+    let mut cards = 0;
+    for action in log.iter() {
+        if let Some(card) = action.card {
+            assert_eq!(replay_card(&env, &client, session_id, &player1, &player2, &action), card);
+            cards += 1;
+        }
+    }
+    assert!(cards > 4);
+
+    // Replayed hands match the round history
+    let history = client.get_round_history(&session_id);
+    for record in history.iter() {
+        let mut hand = Bytes::new(&env);
+        for action in log.iter() {
+            if action.round == record.round && action.player == Some(player1.clone()) {
+                if let Some(card) = action.card {
+                    hand.push_back(card as u8);
+                }
+            }
+        }
+        assert_eq!(hand, record.player1_hand);
+    }
+}
+
+#[test]
+fn test_table_action_log() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (players, points) = table_players(&env, 3);
+
+    let session_id = 62u32;
+    client.start_table(&session_id, &players, &points);
+    for player in players.iter() {
+        client.table_stick(&session_id, &player);
+    }
+    client.reveal_table(&session_id);
+
+    let log = client.get_action_log(&session_id);
+    assert_eq!(log.len(), 3 * 2 + 3 + 1);
+    assert_eq!(log.get(6).unwrap().kind, ActionKind::Stick);
+    assert_eq!(log.get(9).unwrap().kind, ActionKind::Reveal);
+}
+
+#[test]
+fn test_cannot_get_action_log_nonexistent_game() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    let result = client.try_get_action_log(&999);
+    assert_twenty_one_error(&result, Error::GameNotFound);
+}
+
 // ============================================================================
 // Self-Play Prevention Test
 // ============================================================================
@@ -867,6 +1978,6 @@ fn test_cannot_play_against_self() {
 
     let session_id = 29u32;
     // Try to start game where player1 plays against themselves
-    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
+    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000, &Vec::new(&client.env));
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
@@ -42,6 +42,11 @@ interface GameState {
   player2_stuck: boolean;
   winner: string | null;
   round: number;
+  drawn: boolean;
+  aborted: boolean;
+  match_target: number;
+  player1_score: number;
+  player2_score: number;
 }
 
 // Card component with suit and value
@@ -196,7 +201,7 @@ export function TwentyOneGame({
         }
 
         // Determine game phase
-        if (game.winner) {
+        if (game.winner || game.drawn || game.aborted) {
           setGamePhase('complete');
         } else if (game.player1_stuck && game.player2_stuck) {
           setGamePhase('reveal');
@@ -218,11 +223,11 @@ export function TwentyOneGame({
   }, [sessionId, gamePhase]);
 
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && (gameState?.winner || gameState?.drawn)) {
       console.log('Game completed! Refreshing standings...');
       onStandingsRefresh();
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.winner, gameState?.drawn]);
 
   // Handle initial values (similar to NumberGuess)
   useEffect(() => {
@@ -594,10 +599,10 @@ export function TwentyOneGame({
         setGameState(game);
         setLoadSessionId('');
 
-        if (game.winner) {
+        if (game.winner || game.drawn || game.aborted) {
           setGamePhase('complete');
-          const isWinner = normalizeAddress(game.winner) === normalizedUserAddress;
-          setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
+          const isWinner = !!game.winner && normalizeAddress(game.winner) === normalizedUserAddress;
+          setSuccess(isWinner ? '🎉 You won this game!' : game.winner ? 'Game complete. Winner revealed.' : 'Game complete. No winner.');
         } else if (game.player1_stuck && game.player2_stuck) {
           setGamePhase('reveal');
           setSuccess('Game loaded! Both players have stuck. You can reveal the winner.');
@@ -696,17 +701,6 @@ export function TwentyOneGame({
     });
   };
 
-  const waitForWinner = async () => {
-    let updatedGame = await twentyOneService.getGame(sessionId);
-    let attempts = 0;
-    while (attempts < 5 && (!updatedGame || !updatedGame.winner)) {
-      await new Promise((resolve) => setTimeout(resolve, 1000));
-      updatedGame = await twentyOneService.getGame(sessionId);
-      attempts += 1;
-    }
-    return updatedGame;
-  };
-
   const handleRevealWinner = async () => {
     await runAction(async () => {
       try {
@@ -715,14 +709,24 @@ export function TwentyOneGame({
         setSuccess(null);
 
         const signer = getContractSigner();
-        const winnerResult = await twentyOneService.revealWinner(sessionId, userAddress, signer);
-
-        await waitForWinner();
+        const result = await twentyOneService.revealWinner(sessionId, userAddress, signer);
+        const updatedGame = await twentyOneService.getGame(sessionId);
         await loadGameState();
 
-        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
-        const isWinner = normalizeAddress(winner) === normalizedUserAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        // The result is the round's; the match may go on with freshly dealt hands
+        const won =
+          (result.tag === 'Player1Won' && isPlayer1) || (result.tag === 'Player2Won' && isPlayer2);
+        if (updatedGame?.winner || updatedGame?.drawn) {
+          if (updatedGame.drawn) {
+            setSuccess('Game complete! The match is a draw.');
+          } else {
+            const isWinner = normalizeAddress(updatedGame.winner) === normalizedUserAddress;
+            setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+          }
+        } else {
+          const round = result.tag === 'Draw' ? 'Round tied.' : won ? 'You won the round!' : 'Round lost.';
+          setSuccess(`${round} Next round dealt.`);
+        }
 
         onStandingsRefresh();
       } catch (err) {
@@ -772,7 +776,7 @@ export function TwentyOneGame({
           </div>
           <button
             onClick={() => {
-              if (gameState?.winner) {
+              if (gameState?.winner || gameState?.drawn) {
                 onGameComplete();
               }
               onBack();
@@ -1175,6 +1179,15 @@ export function TwentyOneGame({
               </div>
             </div>
 
+            {gameState.drawn && (
+              <div className="p-6 bg-gray-100 border-2 border-gray-300 rounded-xl shadow-lg mb-6">
+                <p className="text-sm font-bold uppercase tracking-wide text-gray-600 mb-2">Result</p>
+                <p className="text-2xl font-black text-gray-800">
+                  Draw: {gameState.player1_score} - {gameState.player2_score}
+                </p>
+              </div>
+            )}
+
             {gameState.winner && (
               <div className="p-6 bg-gradient-to-r from-green-100 to-emerald-100 border-2 border-green-300 rounded-xl shadow-lg mb-6">
                 <p className="text-sm font-bold uppercase tracking-wide text-gray-600 mb-2">Winner</p>
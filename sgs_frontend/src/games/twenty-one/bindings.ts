@@ -39,40 +39,193 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyStuck"},
+  4: {message:"GameAlreadyEnded"},
+  5: {message:"PlayerBusted"},
+  6: {message:"BothPlayersNotStuck"},
+  7: {message:"OpponentNotStuck"},
+  9: {message:"SelfPlay"},
+  10: {message:"RoundOverflow"},
+  11: {message:"InvalidHandData"},
+  12: {message:"InvalidMaxRounds"},
+  13: {message:"InvalidMatchTarget"},
+  14: {message:"InvalidSeatCount"},
+  15: {message:"DuplicatePlayer"},
+  16: {message:"SeatsNotFinished"},
+  17: {message:"InvalidSideBet"},
+  18: {message:"DeadlineNotReached"},
+  19: {message:"NoIdlePlayer"},
+  20: {message:"InvalidTimeout"},
+  21: {message:"CardsNotDealt"},
+  22: {message:"CardsAlreadyDealt"},
+  23: {message:"AlreadyCommitted"},
+  24: {message:"OpponentNotCommitted"},
+  25: {message:"InvalidEntropy"},
+  26: {message:"AlreadyRevealed"}
+}
+
+/**
+ * Outcome of a single round (or of the match, once the game has ended).
+ * 
+ * While the match is still open, any result means the round was scored and new
+ * hands were dealt for the next one; once `Game::drawn` is set, `Draw` means
+ * the match settled as a draw.
+ */
+export type RoundResult = {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Draw", values: void};
+
 export interface Game {
+  aborted: boolean;
+  deadline: u32;
+  drawn: boolean;
+  match_target: u32;
+  max_rounds: u32;
   player1: string;
+  player1_commitment: Option<Buffer>;
+  player1_entropy: Option<Buffer>;
   player1_hand: Buffer;
   player1_points: i128;
+  player1_score: u32;
   player1_stuck: boolean;
   player2: string;
+  player2_commitment: Option<Buffer>;
+  player2_entropy: Option<Buffer>;
   player2_hand: Buffer;
   player2_points: i128;
+  player2_score: u32;
   player2_stuck: boolean;
   round: u32;
+  timeout_ledgers: u32;
   winner: Option<string>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyStuck"},
-  4: {message:"GameAlreadyEnded"},
-  5: {message:"PlayerBusted"},
-  6: {message:"BothPlayersNotStuck"},
-  7: {message:"OpponentNotStuck"},
-  8: {message:"Draw"},
-  9: {message:"SelfPlay"},
-  10: {message:"RoundOverflow"},
-  11: {message:"InvalidHandData"}
+/**
+ * A completed round, as stored in the round history
+ */
+export interface RoundRecord {
+  player1_hand: Buffer;
+  player1_value: u32;
+  player2_hand: Buffer;
+  player2_value: u32;
+  result: RoundResult;
+  round: u32;
+}
+
+export type ActionKind = {tag: "Deal", values: void} | {tag: "Hit", values: void} | {tag: "Stick", values: void} | {tag: "Reveal", values: void};
+
+/**
+ * An entry in a session's append-only action log
+ */
+export interface Action {
+  card: Option<u32>;
+  kind: ActionKind;
+  player: Option<string>;
+  round: u32;
+  seed_index: u32;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Side bets that can be committed at `start_game`
+ */
+export type SideBetKind = {tag: "PerfectPairs", values: void} | {tag: "TwentyOnePlusThree", values: void} | {tag: "Insurance", values: void};
+
+export interface SideBet {
+  kind: SideBetKind;
+  player: string;
+  wager: i128;
+}
+
+/**
+ * A settled side bet. `payout` is the total returned to the player:
+ * 0 when lost, the wager when void, and wager * (odds + 1) when won.
+ */
+export interface SideBetResult {
+  kind: SideBetKind;
+  payout: i128;
+  player: string;
+  wager: i128;
+}
+
+/**
+ * One player's seat at a multi-seat table
+ */
+export interface Seat {
+  busted: boolean;
+  forfeited: boolean;
+  hand: Buffer;
+  player: string;
+  points: i128;
+  stuck: boolean;
+}
+
+export interface Table {
+  aborted: boolean;
+  deadline: u32;
+  ranks: Option<Array<u32>>;
+  seats: Array<Seat>;
+  timeout_ledgers: u32;
+}
+
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "Table", values: readonly [u32]} | {tag: "SideBets", values: readonly [u32]} | {tag: "PendingSideBets", values: readonly [u32]} | {tag: "ActionLog", values: readonly [u32]} | {tag: "RoundHistory", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "MaxRounds", values: void} | {tag: "MatchTarget", values: void} | {tag: "TimeoutLedgers", values: void};
 
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * Each player is dealt 2 cards to start the first round of the match. With
+   * side bets, the deal waits for both players' entropy (see
+   * `commit_entropy`), and the bets settle when the cards are dealt.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `side_bets` - Optional side bets (at most one of each kind per player)
+   * 
+   * A player placing side bets also authorizes their own bets, in order, as
+   * a third argument after `session_id` and their points.
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points, side_bets}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, side_bets: Array<SideBet>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a commit_entropy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to the entropy a player mixes into the first deal of a game with
+   * side bets. The commitment is the keccak256 hash of 32 secret bytes,
+   * revealed with `reveal_entropy` once both players have committed.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the committing player
+   * * `commitment` - keccak256 hash of the player's entropy
+   */
+  commit_entropy: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_entropy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the entropy committed with `commit_entropy`. Neither player can
+   * reveal until both have committed, so the last reveal cannot be chosen
+   * to steer the deal. Once both players have revealed, the first round is
+   * dealt from a seed mixing both entropies and the side bets are settled.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `entropy` - The 32 bytes whose keccak256 hash was committed
+   */
+  reveal_entropy: ({session_id, player, entropy}: {session_id: u32, player: string, entropy: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Player draws another card ("hit").
-   * If the player's hand value exceeds 21, they bust and lose immediately.
+   * If the player's hand value exceeds 21, they bust and lose the round immediately.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
@@ -92,31 +245,72 @@ export interface Client {
   stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the current round.
+   * Can only be called after both players have stuck.
+   * This calculates hand values and determines the round winner (closest to 21).
+   * 
+   * The round is scored and recorded in the round history. If a player has
+   * reached the match target, the outcome is submitted to the GameHub;
+   * otherwise new hands are dealt for the next round. A tied round scores for
+   * nobody. Once the game's `max_rounds` is reached, the match is settled on
+   * score (a level score settles as a draw through the GameHub).
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * `RoundResult` - The result of the round just played, or of the match if
+   * it has already ended
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RoundResult>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a match whose deadline has passed with a player still to stick.
+   * If only one player has stuck, they win the match by forfeit; if neither
+   * has, the match is settled as a draw. Once both have stuck, use
+   * `reveal_winner` instead. Anyone may call this.
+   * 
+   * Before a side bet game is dealt, the player further behind in the
+   * entropy exchange (`commit_entropy`, then `reveal_entropy`) loses the
+   * match and their side bets, and the opponent's side bets are void; if
+   * both are equally far along, the match is a draw and every bet is void.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `RoundResult` - The result of the match
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_timeout: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RoundResult>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a resign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede the match. The opponent wins and the session is settled
+   * through the Game Hub. Resigning before a side bet game is dealt also
+   * forfeits the resigning player's side bets and voids the opponent's.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the resigning player
+   * 
+   * # Returns
+   * * `RoundResult` - The result of the match
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  resign: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RoundResult>>>
+
+  /**
+   * Construct and simulate a abort transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel the match by agreement. Both players must sign; the session is
+   * cancelled through the Game Hub with no result and all points refunded.
+   * Side bets settled at the deal are not affected; aborting before the
+   * deal voids them.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   */
+  abort: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -130,6 +324,157 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_round_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the history of completed rounds in a match.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Vec<RoundRecord>` - Completed rounds, oldest first
+   */
+  get_round_history: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<RoundRecord>>>>
+
+  /**
+   * Construct and simulate a get_side_bets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the settled side bets of a game. Empty until the bets settle when
+   * the first round is dealt (or the game ends before the deal).
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Vec<SideBetResult>` - Side bets in the order they were placed
+   */
+  get_side_bets: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<SideBetResult>>>>
+
+  /**
+   * Construct and simulate a get_action_log transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the append-only action log of a game or table.
+   * Together with the seed derivation in the module docs, this is enough to
+   * replay a session card by card.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game or table
+   * 
+   * # Returns
+   * * `Vec<Action>` - Every deal, hit, stick and reveal, oldest first
+   */
+  get_action_log: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<Action>>>>
+
+  /**
+   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current hand value for a player.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player
+   * 
+   * # Returns
+   * * `u32` - The total value of the player's hand
+   */
+  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a single-hand table game for 2-6 players with points.
+   * This creates a session in the Game Hub through its N-player interface and
+   * locks points before dealing 2 cards to every seat.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `players` - Seated players, in seat order
+   * * `points` - Points amount committed by each player, in seat order
+   */
+  start_table: ({session_id, players, points}: {session_id: u32, players: Array<string>, points: Array<i128>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a table_hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seated player draws another card ("hit").
+   * If the hand value exceeds 21, the seat busts and is out of the hand.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   * * `player` - Address of the player drawing a card
+   */
+  table_hit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a table_stick transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seated player chooses to stick (end their turn with current hand).
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   * * `player` - Address of the player sticking
+   */
+  table_stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rank the table and submit the ranking to the GameHub.
+   * Can only be called once every seat has stuck or busted.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   * 
+   * # Returns
+   * * `Vec<u32>` - Rank of each seat in seat order (1 = best; ties share a rank)
+   */
+  reveal_table: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a claim_table_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a table whose deadline has passed with seats still to stick.
+   * Every idle seat forfeits and is ranked below all other seats; the rest
+   * are ranked as in `reveal_table`. Once every seat has stuck or busted,
+   * use `reveal_table` instead. Anyone may call this.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   * 
+   * # Returns
+   * * `Vec<u32>` - Rank of each seat in seat order (1 = best; ties share a rank)
+   */
+  claim_table_timeout: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a table_resign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede a seat at a table. The seat forfeits and is ranked below every
+   * other seat; the rest of the table plays on. If only one seat is left
+   * in the hand, it wins and the table is settled at once.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   * * `player` - Address of the resigning player
+   * 
+   * # Returns
+   * * `Option<Vec<u32>>` - The ranking, if the resignation settled the table
+   */
+  table_resign: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<Array<u32>>>>>
+
+  /**
+   * Construct and simulate a table_abort transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a table by agreement. Every seated player must sign; the session
+   * is cancelled through the Game Hub with no result and all points refunded.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   */
+  table_abort: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get table information.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the table
+   * 
+   * # Returns
+   * * `Table` - The table state (includes ranks after the table is revealed)
+   */
+  get_table: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Table>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -149,50 +494,90 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * Each player is dealt 2 cards to start.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `new_hub` - The new GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have stuck.
-   * This calculates hand values, determines the winner (closest to 21),
-   * and handles draws by dealing new hands.
+   * Construct and simulate a get_max_rounds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of rounds new games may deal before the match is settled on score
+   * 
+   * # Returns
+   * * `u32` - The configured round limit
+   */
+  get_max_rounds: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_max_rounds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the number of rounds new games may deal before the match is settled on score.
+   * Games already in progress keep the limit they started with.
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
+   * * `max_rounds` - Round limit (must be at least 1 and at least the match target)
+   */
+  set_max_rounds: ({max_rounds}: {max_rounds: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_match_target transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of round wins new games need to win the match
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `u32` - The configured match target
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  get_match_target: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current hand value for a player.
+   * Construct and simulate a set_match_target transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the number of round wins new games need to win the match
+   * (e.g. 2 for best-of-3). Games already in progress keep their target.
+   * To raise the target above the round limit, raise the limit first.
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player
+   * * `match_target` - Round wins needed (must be at least 1 and at most the round limit)
+   */
+  set_match_target: ({match_target}: {match_target: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_timeout_ledgers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of ledgers players in new games have to act before the
+   * opponent can claim a timeout
    * 
    * # Returns
-   * * `u32` - The total value of the player's hand
+   * * `u32` - The configured timeout
    */
-  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  get_timeout_ledgers: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_timeout_ledgers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the number of ledgers players in new games have to act before the
+   * opponent can claim a timeout. Games already in progress keep their timeout.
+   * 
+   * # Arguments
+   * * `timeout_ledgers` - Ledgers allowed per action, 1 to `MAX_TIMEOUT_LEDGERS`
+   */
+  set_timeout_ledgers: ({timeout_ledgers}: {timeout_ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
+   * 
+   * # Arguments
+   * * `new_wasm_hash` - The hash of the new WASM binary
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -214,35 +599,88 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfaGFuZAAAAA4AAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAACFNlbGZQbGF5AAAACQAAAAAAAAANUm91bmRPdmVyZmxvdwAAAAAAAAoAAAAAAAAAD0ludmFsaWRIYW5kRGF0YQAAAAALAAAAAAAAABBJbnZhbGlkTWF4Um91bmRzAAAADAAAAAAAAAASSW52YWxpZE1hdGNoVGFyZ2V0AAAAAAANAAAAAAAAABBJbnZhbGlkU2VhdENvdW50AAAADgAAAAAAAAAPRHVwbGljYXRlUGxheWVyAAAAAA8AAAAAAAAAEFNlYXRzTm90RmluaXNoZWQAAAAQAAAAAAAAAA5JbnZhbGlkU2lkZUJldAAAAAAAEQAAAAAAAAASRGVhZGxpbmVOb3RSZWFjaGVkAAAAAAASAAAAAAAAAAxOb0lkbGVQbGF5ZXIAAAATAAAAAAAAAA5JbnZhbGlkVGltZW91dAAAAAAAFAAAAAAAAAANQ2FyZHNOb3REZWFsdAAAAAAAABUAAAAAAAAAEUNhcmRzQWxyZWFkeURlYWx0AAAAAAAAFgAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAABcAAAAAAAAAFE9wcG9uZW50Tm90Q29tbWl0dGVkAAAAGAAAAAAAAAAOSW52YWxpZEVudHJvcHkAAAAAABkAAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAAa",
+        "AAAAAgAAAPtPdXRjb21lIG9mIGEgc2luZ2xlIHJvdW5kIChvciBvZiB0aGUgbWF0Y2gsIG9uY2UgdGhlIGdhbWUgaGFzIGVuZGVkKS4KCldoaWxlIHRoZSBtYXRjaCBpcyBzdGlsbCBvcGVuLCBhbnkgcmVzdWx0IG1lYW5zIHRoZSByb3VuZCB3YXMgc2NvcmVkIGFuZCBuZXcKaGFuZHMgd2VyZSBkZWFsdCBmb3IgdGhlIG5leHQgb25lOyBvbmNlIGBHYW1lOjpkcmF3bmAgaXMgc2V0LCBgRHJhd2AgbWVhbnMKdGhlIG1hdGNoIHNldHRsZWQgYXMgYSBkcmF3LgAAAAAAAAAAC1JvdW5kUmVzdWx0AAAAAAMAAAAAAAAAAAAAAApQbGF5ZXIxV29uAAAAAAAAAAAAAAAAAApQbGF5ZXIyV29uAAAAAAAAAAAAAAAAAAREcmF3",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAWAAAAAAAAAAdhYm9ydGVkAAAAAAEAAAAAAAAACGRlYWRsaW5lAAAABAAAAAAAAAAFZHJhd24AAAAAAAABAAAAAAAAAAxtYXRjaF90YXJnZXQAAAAEAAAAAAAAAAptYXhfcm91bmRzAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAEnBsYXllcjFfY29tbWl0bWVudAAAAAAD6AAAA+4AAAAgAAAAAAAAAA9wbGF5ZXIxX2VudHJvcHkAAAAD6AAAA+4AAAAgAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMV9zY29yZQAAAAAAAAQAAAAAAAAADXBsYXllcjFfc3R1Y2sAAAAAAAABAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAAEnBsYXllcjJfY29tbWl0bWVudAAAAAAD6AAAA+4AAAAgAAAAAAAAAA9wbGF5ZXIyX2VudHJvcHkAAAAD6AAAA+4AAAAgAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAAAQAAAAAAAAADXBsYXllcjJfc3R1Y2sAAAAAAAABAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAAD3RpbWVvdXRfbGVkZ2VycwAAAAAEAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAQAAADFBIGNvbXBsZXRlZCByb3VuZCwgYXMgc3RvcmVkIGluIHRoZSByb3VuZCBoaXN0b3J5AAAAAAAAAAAAAAtSb3VuZFJlY29yZAAAAAAGAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAAAAAAA1wbGF5ZXIxX3ZhbHVlAAAAAAAABAAAAAAAAAAMcGxheWVyMl9oYW5kAAAADgAAAAAAAAANcGxheWVyMl92YWx1ZQAAAAAAAAQAAAAAAAAABnJlc3VsdAAAAAAH0AAAAAtSb3VuZFJlc3VsdAAAAAAAAAAABXJvdW5kAAAAAAAABA==",
+        "AAAAAgAAAAAAAAAAAAAACkFjdGlvbktpbmQAAAAAAAQAAAAAAAAAAAAAAAREZWFsAAAAAAAAAAAAAAADSGl0AAAAAAAAAAAAAAAABVN0aWNrAAAAAAAAAAAAAAAAAAAGUmV2ZWFsAAA=",
+        "AAAAAQAAAC5BbiBlbnRyeSBpbiBhIHNlc3Npb24ncyBhcHBlbmQtb25seSBhY3Rpb24gbG9nAAAAAAAAAAAABkFjdGlvbgAAAAAABQAAAAAAAAAEY2FyZAAAA+gAAAAEAAAAAAAAAARraW5kAAAH0AAAAApBY3Rpb25LaW5kAAAAAAAAAAAABnBsYXllcgAAAAAD6AAAABMAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAKc2VlZF9pbmRleAAAAAAABA==",
+        "AAAAAgAAAC9TaWRlIGJldHMgdGhhdCBjYW4gYmUgY29tbWl0dGVkIGF0IGBzdGFydF9nYW1lYAAAAAAAAAAAC1NpZGVCZXRLaW5kAAAAAAMAAAAAAAAAJ1RoZSBwbGF5ZXIncyBmaXJzdCB0d28gY2FyZHMgYXJlIGEgcGFpcgAAAAAMUGVyZmVjdFBhaXJzAAAAAAAAAFFUaGUgcGxheWVyJ3MgZmlyc3QgdHdvIGNhcmRzIHBsdXMgdGhlIG9wcG9uZW50J3MgdXAgY2FyZCBtYWtlIHRyaXBzIG9yIGEgc3RyYWlnaHQAAAAAAAASVHdlbnR5T25lUGx1c1RocmVlAAAAAAAAAAAAT1RoZSBvcHBvbmVudCdzIHVwIGNhcmQgaXMgYW4gQWNlIGFuZCB0aGVpciBvcGVuaW5nIGhhbmQgaXMgQWNlICsgdGVuLXZhbHVlIGNhcmQAAAAACUluc3VyYW5jZQAAAA==",
+        "AAAAAQAAAAAAAAAAAAAAB1NpZGVCZXQAAAAAAwAAAAAAAAAEa2luZAAAB9AAAAALU2lkZUJldEtpbmQAAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXdhZ2VyAAAAAAAACw==",
+        "AAAAAQAAAIRBIHNldHRsZWQgc2lkZSBiZXQuIGBwYXlvdXRgIGlzIHRoZSB0b3RhbCByZXR1cm5lZCB0byB0aGUgcGxheWVyOgowIHdoZW4gbG9zdCwgdGhlIHdhZ2VyIHdoZW4gdm9pZCwgYW5kIHdhZ2VyICogKG9kZHMgKyAxKSB3aGVuIHdvbi4AAAAAAAAADVNpZGVCZXRSZXN1bHQAAAAAAAAEAAAAAAAAAARraW5kAAAH0AAAAAtTaWRlQmV0S2luZAAAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAV3YWdlcgAAAAAAAAs=",
+        "AAAAAQAAACdPbmUgcGxheWVyJ3Mgc2VhdCBhdCBhIG11bHRpLXNlYXQgdGFibGUAAAAAAAAAAARTZWF0AAAABgAAAAAAAAAGYnVzdGVkAAAAAAABAAAAAAAAAAlmb3JmZWl0ZWQAAAAAAAABAAAAAAAAAARoYW5kAAAADgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAABXN0dWNrAAAAAAAAAQ==",
+        "AAAAAQAAAAAAAAAAAAAABVRhYmxlAAAAAAAABQAAAAAAAAAHYWJvcnRlZAAAAAABAAAAAAAAAAhkZWFkbGluZQAAAAQAAAAAAAAABXJhbmtzAAAAAAAD6AAAA+oAAAAEAAAAAAAAAAVzZWF0cwAAAAAAA+oAAAfQAAAABFNlYXQAAAAAAAAAD3RpbWVvdXRfbGVkZ2VycwAAAAAE",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAACwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAABVRhYmxlAAAAAAAAAQAAAAQAAAABAAAAAAAAAAhTaWRlQmV0cwAAAAEAAAAEAAAAAQAAAAAAAAAPUGVuZGluZ1NpZGVCZXRzAAAAAAEAAAAEAAAAAQAAAAAAAAAJQWN0aW9uTG9nAAAAAAAAAQAAAAQAAAABAAAAAAAAAAxSb3VuZEhpc3RvcnkAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAACU1heFJvdW5kcwAAAAAAAAAAAAAAAAAAC01hdGNoVGFyZ2V0AAAAAAAAAAAAAAAADlRpbWVvdXRMZWRnZXJzAAA=",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAA7VTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0IHRoZSBmaXJzdCByb3VuZCBvZiB0aGUgbWF0Y2guIFdpdGgKc2lkZSBiZXRzLCB0aGUgZGVhbCB3YWl0cyBmb3IgYm90aCBwbGF5ZXJzJyBlbnRyb3B5IChzZWUKYGNvbW1pdF9lbnRyb3B5YCksIGFuZCB0aGUgYmV0cyBzZXR0bGUgd2hlbiB0aGUgY2FyZHMgYXJlIGRlYWx0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBzaWRlX2JldHNgIC0gT3B0aW9uYWwgc2lkZSBiZXRzIChhdCBtb3N0IG9uZSBvZiBlYWNoIGtpbmQgcGVyIHBsYXllcikKCkEgcGxheWVyIHBsYWNpbmcgc2lkZSBiZXRzIGFsc28gYXV0aG9yaXplcyB0aGVpciBvd24gYmV0cywgaW4gb3JkZXIsIGFzCmEgdGhpcmQgYXJndW1lbnQgYWZ0ZXIgYHNlc3Npb25faWRgIGFuZCB0aGVpciBwb2ludHMuAAAAAAAACnN0YXJ0X2dhbWUAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAACXNpZGVfYmV0cwAAAAAAA+oAAAfQAAAAB1NpZGVCZXQAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAWtDb21taXQgdG8gdGhlIGVudHJvcHkgYSBwbGF5ZXIgbWl4ZXMgaW50byB0aGUgZmlyc3QgZGVhbCBvZiBhIGdhbWUgd2l0aApzaWRlIGJldHMuIFRoZSBjb21taXRtZW50IGlzIHRoZSBrZWNjYWsyNTYgaGFzaCBvZiAzMiBzZWNyZXQgYnl0ZXMsCnJldmVhbGVkIHdpdGggYHJldmVhbF9lbnRyb3B5YCBvbmNlIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbW1pdHRpbmcgcGxheWVyCiogYGNvbW1pdG1lbnRgIC0ga2VjY2FrMjU2IGhhc2ggb2YgdGhlIHBsYXllcidzIGVudHJvcHkAAAAADmNvbW1pdF9lbnRyb3B5AAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAb5SZXZlYWwgdGhlIGVudHJvcHkgY29tbWl0dGVkIHdpdGggYGNvbW1pdF9lbnRyb3B5YC4gTmVpdGhlciBwbGF5ZXIgY2FuCnJldmVhbCB1bnRpbCBib3RoIGhhdmUgY29tbWl0dGVkLCBzbyB0aGUgbGFzdCByZXZlYWwgY2Fubm90IGJlIGNob3Nlbgp0byBzdGVlciB0aGUgZGVhbC4gT25jZSBib3RoIHBsYXllcnMgaGF2ZSByZXZlYWxlZCwgdGhlIGZpcnN0IHJvdW5kIGlzCmRlYWx0IGZyb20gYSBzZWVkIG1peGluZyBib3RoIGVudHJvcGllcyBhbmQgdGhlIHNpZGUgYmV0cyBhcmUgc2V0dGxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHJldmVhbGluZyBwbGF5ZXIKKiBgZW50cm9weWAgLSBUaGUgMzIgYnl0ZXMgd2hvc2Uga2VjY2FrMjU2IGhhc2ggd2FzIGNvbW1pdHRlZAAAAAAADnJldmVhbF9lbnRyb3B5AAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAB2VudHJvcHkAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAN5QbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSB0aGUgcm91bmQgaW1tZWRpYXRlbHkuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgZHJhd2luZyBhIGNhcmQAAAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAqRSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgY3VycmVudCByb3VuZC4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMgYW5kIGRldGVybWluZXMgdGhlIHJvdW5kIHdpbm5lciAoY2xvc2VzdCB0byAyMSkuCgpUaGUgcm91bmQgaXMgc2NvcmVkIGFuZCByZWNvcmRlZCBpbiB0aGUgcm91bmQgaGlzdG9yeS4gSWYgYSBwbGF5ZXIgaGFzCnJlYWNoZWQgdGhlIG1hdGNoIHRhcmdldCwgdGhlIG91dGNvbWUgaXMgc3VibWl0dGVkIHRvIHRoZSBHYW1lSHViOwpvdGhlcndpc2UgbmV3IGhhbmRzIGFyZSBkZWFsdCBmb3IgdGhlIG5leHQgcm91bmQuIEEgdGllZCByb3VuZCBzY29yZXMgZm9yCm5vYm9keS4gT25jZSB0aGUgZ2FtZSdzIGBtYXhfcm91bmRzYCBpcyByZWFjaGVkLCB0aGUgbWF0Y2ggaXMgc2V0dGxlZCBvbgpzY29yZSAoYSBsZXZlbCBzY29yZSBzZXR0bGVzIGFzIGEgZHJhdyB0aHJvdWdoIHRoZSBHYW1lSHViKS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBSb3VuZFJlc3VsdGAgLSBUaGUgcmVzdWx0IG9mIHRoZSByb3VuZCBqdXN0IHBsYXllZCwgb3Igb2YgdGhlIG1hdGNoIGlmCml0IGhhcyBhbHJlYWR5IGVuZGVkAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAAC1JvdW5kUmVzdWx0AAAAAAM=",
+        "AAAAAAAAAn5TZXR0bGUgYSBtYXRjaCB3aG9zZSBkZWFkbGluZSBoYXMgcGFzc2VkIHdpdGggYSBwbGF5ZXIgc3RpbGwgdG8gc3RpY2suCklmIG9ubHkgb25lIHBsYXllciBoYXMgc3R1Y2ssIHRoZXkgd2luIHRoZSBtYXRjaCBieSBmb3JmZWl0OyBpZiBuZWl0aGVyCmhhcywgdGhlIG1hdGNoIGlzIHNldHRsZWQgYXMgYSBkcmF3LiBPbmNlIGJvdGggaGF2ZSBzdHVjaywgdXNlCmByZXZlYWxfd2lubmVyYCBpbnN0ZWFkLiBBbnlvbmUgbWF5IGNhbGwgdGhpcy4KCkJlZm9yZSBhIHNpZGUgYmV0IGdhbWUgaXMgZGVhbHQsIHRoZSBwbGF5ZXIgZnVydGhlciBiZWhpbmQgaW4gdGhlCmVudHJvcHkgZXhjaGFuZ2UgKGBjb21taXRfZW50cm9weWAsIHRoZW4gYHJldmVhbF9lbnRyb3B5YCkgbG9zZXMgdGhlCm1hdGNoIGFuZCB0aGVpciBzaWRlIGJldHMsIGFuZCB0aGUgb3Bwb25lbnQncyBzaWRlIGJldHMgYXJlIHZvaWQ7IGlmCmJvdGggYXJlIGVxdWFsbHkgZmFyIGFsb25nLCB0aGUgbWF0Y2ggaXMgYSBkcmF3IGFuZCBldmVyeSBiZXQgaXMgdm9pZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBSb3VuZFJlc3VsdGAgLSBUaGUgcmVzdWx0IG9mIHRoZSBtYXRjaAAAAAAADWNsYWltX3RpbWVvdXQAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAAC1JvdW5kUmVzdWx0AAAAAAM=",
+        "AAAAAAAAAWNDb25jZWRlIHRoZSBtYXRjaC4gVGhlIG9wcG9uZW50IHdpbnMgYW5kIHRoZSBzZXNzaW9uIGlzIHNldHRsZWQKdGhyb3VnaCB0aGUgR2FtZSBIdWIuIFJlc2lnbmluZyBiZWZvcmUgYSBzaWRlIGJldCBnYW1lIGlzIGRlYWx0IGFsc28KZm9yZmVpdHMgdGhlIHJlc2lnbmluZyBwbGF5ZXIncyBzaWRlIGJldHMgYW5kIHZvaWRzIHRoZSBvcHBvbmVudCdzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcmVzaWduaW5nIHBsYXllcgoKIyBSZXR1cm5zCiogYFJvdW5kUmVzdWx0YCAtIFRoZSByZXN1bHQgb2YgdGhlIG1hdGNoAAAAAAZyZXNpZ24AAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAH0AAAAAtSb3VuZFJlc3VsdAAAAAAD",
+        "AAAAAAAAARpDYW5jZWwgdGhlIG1hdGNoIGJ5IGFncmVlbWVudC4gQm90aCBwbGF5ZXJzIG11c3Qgc2lnbjsgdGhlIHNlc3Npb24gaXMKY2FuY2VsbGVkIHRocm91Z2ggdGhlIEdhbWUgSHViIHdpdGggbm8gcmVzdWx0IGFuZCBhbGwgcG9pbnRzIHJlZnVuZGVkLgpTaWRlIGJldHMgc2V0dGxlZCBhdCB0aGUgZGVhbCBhcmUgbm90IGFmZmVjdGVkOyBhYm9ydGluZyBiZWZvcmUgdGhlCmRlYWwgdm9pZHMgdGhlbS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUAAAAAAAVhYm9ydAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAJ9HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBoYW5kcyBhbmQgd2lubmVyIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAKlHZXQgdGhlIGhpc3Rvcnkgb2YgY29tcGxldGVkIHJvdW5kcyBpbiBhIG1hdGNoLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYFZlYzxSb3VuZFJlY29yZD5gIC0gQ29tcGxldGVkIHJvdW5kcywgb2xkZXN0IGZpcnN0AAAAAAAAEWdldF9yb3VuZF9oaXN0b3J5AAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAALUm91bmRSZWNvcmQAAAAAAw==",
+        "AAAAAAAAAQdHZXQgdGhlIHNldHRsZWQgc2lkZSBiZXRzIG9mIGEgZ2FtZS4gRW1wdHkgdW50aWwgdGhlIGJldHMgc2V0dGxlIHdoZW4KdGhlIGZpcnN0IHJvdW5kIGlzIGRlYWx0IChvciB0aGUgZ2FtZSBlbmRzIGJlZm9yZSB0aGUgZGVhbCkuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgVmVjPFNpZGVCZXRSZXN1bHQ+YCAtIFNpZGUgYmV0cyBpbiB0aGUgb3JkZXIgdGhleSB3ZXJlIHBsYWNlZAAAAAANZ2V0X3NpZGVfYmV0cwAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAADVNpZGVCZXRSZXN1bHQAAAAAAAAD",
+        "AAAAAAAAAShHZXQgdGhlIGFwcGVuZC1vbmx5IGFjdGlvbiBsb2cgb2YgYSBnYW1lIG9yIHRhYmxlLgpUb2dldGhlciB3aXRoIHRoZSBzZWVkIGRlcml2YXRpb24gaW4gdGhlIG1vZHVsZSBkb2NzLCB0aGlzIGlzIGVub3VnaCB0bwpyZXBsYXkgYSBzZXNzaW9uIGNhcmQgYnkgY2FyZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUgb3IgdGFibGUKCiMgUmV0dXJucwoqIGBWZWM8QWN0aW9uPmAgLSBFdmVyeSBkZWFsLCBoaXQsIHN0aWNrIGFuZCByZXZlYWwsIG9sZGVzdCBmaXJzdAAAAA5nZXRfYWN0aW9uX2xvZwAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAGQWN0aW9uAAAAAAAD",
+        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAWVTdGFydCBhIHNpbmdsZS1oYW5kIHRhYmxlIGdhbWUgZm9yIDItNiBwbGF5ZXJzIHdpdGggcG9pbnRzLgpUaGlzIGNyZWF0ZXMgYSBzZXNzaW9uIGluIHRoZSBHYW1lIEh1YiB0aHJvdWdoIGl0cyBOLXBsYXllciBpbnRlcmZhY2UgYW5kCmxvY2tzIHBvaW50cyBiZWZvcmUgZGVhbGluZyAyIGNhcmRzIHRvIGV2ZXJ5IHNlYXQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyc2AgLSBTZWF0ZWQgcGxheWVycywgaW4gc2VhdCBvcmRlcgoqIGBwb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgZWFjaCBwbGF5ZXIsIGluIHNlYXQgb3JkZXIAAAAAAAALc3RhcnRfdGFibGUAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAAAAAAAAGcG9pbnRzAAAAAAPqAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAANpTZWF0ZWQgcGxheWVyIGRyYXdzIGFub3RoZXIgY2FyZCAoImhpdCIpLgpJZiB0aGUgaGFuZCB2YWx1ZSBleGNlZWRzIDIxLCB0aGUgc2VhdCBidXN0cyBhbmQgaXMgb3V0IG9mIHRoZSBoYW5kLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgdGFibGUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAAAACXRhYmxlX2hpdAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAKhTZWF0ZWQgcGxheWVyIGNob29zZXMgdG8gc3RpY2sgKGVuZCB0aGVpciB0dXJuIHdpdGggY3VycmVudCBoYW5kKS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIHRhYmxlCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgc3RpY2tpbmcAAAALdGFibGVfc3RpY2sAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAP9SYW5rIHRoZSB0YWJsZSBhbmQgc3VibWl0IHRoZSByYW5raW5nIHRvIHRoZSBHYW1lSHViLgpDYW4gb25seSBiZSBjYWxsZWQgb25jZSBldmVyeSBzZWF0IGhhcyBzdHVjayBvciBidXN0ZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSB0YWJsZQoKIyBSZXR1cm5zCiogYFZlYzx1MzI+YCAtIFJhbmsgb2YgZWFjaCBzZWF0IGluIHNlYXQgb3JkZXIgKDEgPSBiZXN0OyB0aWVzIHNoYXJlIGEgcmFuaykAAAAADHJldmVhbF90YWJsZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+        "AAAAAAAAAZRTZXR0bGUgYSB0YWJsZSB3aG9zZSBkZWFkbGluZSBoYXMgcGFzc2VkIHdpdGggc2VhdHMgc3RpbGwgdG8gc3RpY2suCkV2ZXJ5IGlkbGUgc2VhdCBmb3JmZWl0cyBhbmQgaXMgcmFua2VkIGJlbG93IGFsbCBvdGhlciBzZWF0czsgdGhlIHJlc3QKYXJlIHJhbmtlZCBhcyBpbiBgcmV2ZWFsX3RhYmxlYC4gT25jZSBldmVyeSBzZWF0IGhhcyBzdHVjayBvciBidXN0ZWQsCnVzZSBgcmV2ZWFsX3RhYmxlYCBpbnN0ZWFkLiBBbnlvbmUgbWF5IGNhbGwgdGhpcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIHRhYmxlCgojIFJldHVybnMKKiBgVmVjPHUzMj5gIC0gUmFuayBvZiBlYWNoIHNlYXQgaW4gc2VhdCBvcmRlciAoMSA9IGJlc3Q7IHRpZXMgc2hhcmUgYSByYW5rKQAAABNjbGFpbV90YWJsZV90aW1lb3V0AAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+        "AAAAAAAAAX1Db25jZWRlIGEgc2VhdCBhdCBhIHRhYmxlLiBUaGUgc2VhdCBmb3JmZWl0cyBhbmQgaXMgcmFua2VkIGJlbG93IGV2ZXJ5Cm90aGVyIHNlYXQ7IHRoZSByZXN0IG9mIHRoZSB0YWJsZSBwbGF5cyBvbi4gSWYgb25seSBvbmUgc2VhdCBpcyBsZWZ0CmluIHRoZSBoYW5kLCBpdCB3aW5zIGFuZCB0aGUgdGFibGUgaXMgc2V0dGxlZCBhdCBvbmNlLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgdGFibGUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHJlc2lnbmluZyBwbGF5ZXIKCiMgUmV0dXJucwoqIGBPcHRpb248VmVjPHUzMj4+YCAtIFRoZSByYW5raW5nLCBpZiB0aGUgcmVzaWduYXRpb24gc2V0dGxlZCB0aGUgdGFibGUAAAAAAAAMdGFibGVfcmVzaWduAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPoAAAD6gAAAAQAAAAD",
+        "AAAAAAAAAMtDYW5jZWwgYSB0YWJsZSBieSBhZ3JlZW1lbnQuIEV2ZXJ5IHNlYXRlZCBwbGF5ZXIgbXVzdCBzaWduOyB0aGUgc2Vzc2lvbgppcyBjYW5jZWxsZWQgdGhyb3VnaCB0aGUgR2FtZSBIdWIgd2l0aCBubyByZXN1bHQgYW5kIGFsbCBwb2ludHMgcmVmdW5kZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSB0YWJsZQAAAAALdGFibGVfYWJvcnQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAKRHZXQgdGFibGUgaW5mb3JtYXRpb24uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSB0YWJsZQoKIyBSZXR1cm5zCiogYFRhYmxlYCAtIFRoZSB0YWJsZSBzdGF0ZSAoaW5jbHVkZXMgcmFua3MgYWZ0ZXIgdGhlIHRhYmxlIGlzIHJldmVhbGVkKQAAAAlnZXRfdGFibGUAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABVRhYmxlAAAAAAAAAw==",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAUpSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSksCmFuZCBoYW5kbGVzIGRyYXdzIGJ5IGRlYWxpbmcgbmV3IGhhbmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
-        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAIBHZXQgdGhlIG51bWJlciBvZiByb3VuZHMgbmV3IGdhbWVzIG1heSBkZWFsIGJlZm9yZSB0aGUgbWF0Y2ggaXMgc2V0dGxlZCBvbiBzY29yZQoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgY29uZmlndXJlZCByb3VuZCBsaW1pdAAAAA5nZXRfbWF4X3JvdW5kcwAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAOpTZXQgdGhlIG51bWJlciBvZiByb3VuZHMgbmV3IGdhbWVzIG1heSBkZWFsIGJlZm9yZSB0aGUgbWF0Y2ggaXMgc2V0dGxlZCBvbiBzY29yZS4KR2FtZXMgYWxyZWFkeSBpbiBwcm9ncmVzcyBrZWVwIHRoZSBsaW1pdCB0aGV5IHN0YXJ0ZWQgd2l0aC4KCiMgQXJndW1lbnRzCiogYG1heF9yb3VuZHNgIC0gUm91bmQgbGltaXQgKG11c3QgYmUgYXQgbGVhc3QgMSBhbmQgYXQgbGVhc3QgdGhlIG1hdGNoIHRhcmdldCkAAAAAAA5zZXRfbWF4X3JvdW5kcwAAAAAAAQAAAAAAAAAKbWF4X3JvdW5kcwAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAG1HZXQgdGhlIG51bWJlciBvZiByb3VuZCB3aW5zIG5ldyBnYW1lcyBuZWVkIHRvIHdpbiB0aGUgbWF0Y2gKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIGNvbmZpZ3VyZWQgbWF0Y2ggdGFyZ2V0AAAAAAAAEGdldF9tYXRjaF90YXJnZXQAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAASZTZXQgdGhlIG51bWJlciBvZiByb3VuZCB3aW5zIG5ldyBnYW1lcyBuZWVkIHRvIHdpbiB0aGUgbWF0Y2gKKGUuZy4gMiBmb3IgYmVzdC1vZi0zKS4gR2FtZXMgYWxyZWFkeSBpbiBwcm9ncmVzcyBrZWVwIHRoZWlyIHRhcmdldC4KVG8gcmFpc2UgdGhlIHRhcmdldCBhYm92ZSB0aGUgcm91bmQgbGltaXQsIHJhaXNlIHRoZSBsaW1pdCBmaXJzdC4KCiMgQXJndW1lbnRzCiogYG1hdGNoX3RhcmdldGAgLSBSb3VuZCB3aW5zIG5lZWRlZCAobXVzdCBiZSBhdCBsZWFzdCAxIGFuZCBhdCBtb3N0IHRoZSByb3VuZCBsaW1pdCkAAAAAABBzZXRfbWF0Y2hfdGFyZ2V0AAAAAQAAAAAAAAAMbWF0Y2hfdGFyZ2V0AAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAI5HZXQgdGhlIG51bWJlciBvZiBsZWRnZXJzIHBsYXllcnMgaW4gbmV3IGdhbWVzIGhhdmUgdG8gYWN0IGJlZm9yZSB0aGUKb3Bwb25lbnQgY2FuIGNsYWltIGEgdGltZW91dAoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgY29uZmlndXJlZCB0aW1lb3V0AAAAAAATZ2V0X3RpbWVvdXRfbGVkZ2VycwAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAOtTZXQgdGhlIG51bWJlciBvZiBsZWRnZXJzIHBsYXllcnMgaW4gbmV3IGdhbWVzIGhhdmUgdG8gYWN0IGJlZm9yZSB0aGUKb3Bwb25lbnQgY2FuIGNsYWltIGEgdGltZW91dC4gR2FtZXMgYWxyZWFkeSBpbiBwcm9ncmVzcyBrZWVwIHRoZWlyIHRpbWVvdXQuCgojIEFyZ3VtZW50cwoqIGB0aW1lb3V0X2xlZGdlcnNgIC0gTGVkZ2VycyBhbGxvd2VkIHBlciBhY3Rpb24sIDEgdG8gYE1BWF9USU1FT1VUX0xFREdFUlNgAAAAABNzZXRfdGltZW91dF9sZWRnZXJzAAAAAAEAAAAAAAAAD3RpbWVvdXRfbGVkZ2VycwAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    hit: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<void>>,
+        commit_entropy: this.txFromJSON<Result<void>>,
+        reveal_entropy: this.txFromJSON<Result<void>>,
+        hit: this.txFromJSON<Result<void>>,
         stick: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+        reveal_winner: this.txFromJSON<Result<RoundResult>>,
+        claim_timeout: this.txFromJSON<Result<RoundResult>>,
+        resign: this.txFromJSON<Result<RoundResult>>,
+        abort: this.txFromJSON<Result<void>>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_round_history: this.txFromJSON<Result<Array<RoundRecord>>>,
+        get_side_bets: this.txFromJSON<Result<Array<SideBetResult>>>,
+        get_action_log: this.txFromJSON<Result<Array<Action>>>,
+        get_hand_value: this.txFromJSON<Result<u32>>,
+        start_table: this.txFromJSON<Result<void>>,
+        table_hit: this.txFromJSON<Result<void>>,
+        table_stick: this.txFromJSON<Result<void>>,
+        reveal_table: this.txFromJSON<Result<Array<u32>>>,
+        claim_table_timeout: this.txFromJSON<Result<Array<u32>>>,
+        table_resign: this.txFromJSON<Result<Option<Array<u32>>>>,
+        table_abort: this.txFromJSON<Result<void>>,
+        get_table: this.txFromJSON<Result<Table>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>,
-        get_hand_value: this.txFromJSON<Result<u32>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        get_max_rounds: this.txFromJSON<u32>,
+        set_max_rounds: this.txFromJSON<Result<void>>,
+        get_match_target: this.txFromJSON<u32>,
+        set_match_target: this.txFromJSON<Result<void>>,
+        get_timeout_ledgers: this.txFromJSON<u32>,
+        set_timeout_ledgers: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-import { Client as TwentyOneClient } from './bindings';
+import { Client as TwentyOneClient, type RoundResult } from './bindings';
 import { TWENTY_ONE_CONTRACT, NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, Address, authorizeEntry, xdr } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
@@ -113,6 +113,7 @@ export class TwentyOneService {
       player2,
       player1_points: player1Points,
       player2_points: player2Points,
+      side_bets: [],
     }, DEFAULT_METHOD_OPTIONS);
 
     console.log('[prepareStartGame] Transaction built and simulated');
@@ -225,6 +226,7 @@ export class TwentyOneService {
       player2,
       player1_points: gameParams.player1Points,
       player2_points: player2Points,
+      side_bets: [],
     }, DEFAULT_METHOD_OPTIONS);
 
     // Import Player 1's signed auth entry
@@ -274,6 +276,7 @@ export class TwentyOneService {
       // - signer address (from credentials)
       // - session_id (arg 0)
       // - signer points (arg 1)
+      // A third arg would be the signer's side bets, which this flow never places
       const credentials = authEntry.credentials();
       if (credentials.switch().name !== 'sorobanCredentialsAddress') {
         throw new Error(`Unsupported credentials type: ${credentials.switch().name}`);
@@ -355,18 +358,70 @@ export class TwentyOneService {
   }
 
   /**
-   * Reveal the winner (can be called by either player)
+   * Score the current round (can be called by either player)
+   * Returns the round's result; once the match has ended, the match result
    */
   async revealWinner(
     sessionId: number,
     player: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
-  ) {
+  ): Promise<RoundResult> {
     const client = this.createSigningClient(player, signer);
     const tx = await client.reveal_winner({
       session_id: sessionId,
     }, DEFAULT_METHOD_OPTIONS);
 
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+    return sentTx.result.unwrap();
+  }
+
+  /**
+   * Side bet games: commit to keccak256(entropy) before the first deal
+   */
+  async commitEntropy(
+    sessionId: number,
+    player: string,
+    commitment: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.commit_entropy({
+      session_id: sessionId,
+      player,
+      commitment,
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+    return sentTx.result;
+  }
+
+  /**
+   * Side bet games: reveal the committed entropy once both players have
+   * committed; the second reveal deals the first round and settles side bets
+   */
+  async revealEntropy(
+    sessionId: number,
+    player: string,
+    entropy: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.reveal_entropy({
+      session_id: sessionId,
+      player,
+      entropy,
+    }, DEFAULT_METHOD_OPTIONS);
+
     const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
     const sentTx = await signAndSendViaLaunchtube(
       tx,
@@ -39,127 +39,369 @@ export const networks = {
 } as const
 
 
-export interface Game {
+export const Errors = {
+  1: {message:"LobbyNotFound"},
+  2: {message:"LobbyInactive"},
+  3: {message:"AlreadyJoined"},
+  4: {message:"AlreadyCommitted"},
+  5: {message:"NotPlayer"},
+  6: {message:"WrongFloor"},
+  7: {message:"BadNonce"},
+  8: {message:"NotWaiting"},
+  9: {message:"GameFinished"},
+  10: {message:"MissingCommit"},
+  11: {message:"VerifyingKeyNotSet"},
+  12: {message:"InvalidVerifyingKey"},
+  13: {message:"InvalidCommit"},
+  14: {message:"LobbyExists"},
+  15: {message:"InvalidDoor"},
+  16: {message:"LobbyExpired"},
+  17: {message:"NotExpired"},
+  18: {message:"InvalidStake"},
+  19: {message:"StakeMismatch"},
+  20: {message:"InvalidConfig"},
+  21: {message:"InvalidProof"},
+  22: {message:"CoolingDown"},
+  23: {message:"NoItem"},
+  24: {message:"InvalidItemUse"},
+  25: {message:"AttemptPending"},
+  26: {message:"NoPendingAttempt"}
+}
+
+export type Status = {tag: "Waiting", values: void} | {tag: "Active", values: void} | {tag: "Finished", values: void} | {tag: "Cancelled", values: void} | {tag: "Abandoned", values: void} | {tag: "Aborted", values: void};
+
+/**
+ * Items found in the dungeon
+ */
+export type Item = {tag: "Key", values: void} | {tag: "Torch", values: void} | {tag: "Trap", values: void};
+
+/**
+ * An item lying on a floor; each player picks it up the first time they
+ * clear that floor
+ */
+export interface ItemGrant {
+  floor: u32;
+  item: Item;
+}
+
+export interface Inventory {
+  keys: u32;
+  torches: u32;
+  traps: u32;
+}
+
+/**
+ * Dungeon shape, fixed at lobby creation
+ */
+export interface DungeonConfig {
+  /**
+   * Ledgers a player must wait after a failed attempt; 0 = no cooldown
+   */
+  cooldown_ledgers: u32;
+  /**
+   * Number of floors, 1..=MAX_DEPTH; clearing the last one wins
+   */
+  depth: u32;
+  /**
+   * Cooperative gate floors in ascending order: both players must clear a
+   * gate floor before either moves past it. The last floor decides the race
+   * and cannot be a gate.
+   */
+  gate_floors: Array<u32>;
+  /**
+   * Items placed on floors below the last one
+   */
+  item_grants: Array<ItemGrant>;
+  /**
+   * Whether a failed attempt knocks the player back one floor. Gates are
+   * checkpoints: nobody is knocked off or onto a gate floor.
+   */
+  knockback: boolean;
+  /**
+   * Failed attempts allowed on one floor before the player forfeits; 0 = no limit
+   */
+  max_failures: u32;
+}
+
+export interface PlayerState {
+  /**
+   * Gate floors this player has cleared; bit `floor - 1` is set per floor
+   */
+  cleared_gates: u32;
+  commit: Option<Buffer>;
+  /**
+   * First ledger the player may attempt a door again after a failure
+   */
+  cooldown_until: u32;
+  /**
+   * Verified failed attempts over the whole game
+   */
+  failed_attempts: u32;
+  floor: u32;
+  /**
+   * Verified failed attempts on the current floor
+   */
+  floor_failures: u32;
+  inventory: Inventory;
+  /**
+   * Ledger of the player's last commit or door attempt
+   */
+  last_active: u32;
+  last_nonce: u32;
+  /**
+   * Floors whose items this player has picked up; bit `floor - 1` per floor
+   */
+  looted_floors: u32;
+  /**
+   * Door tried on the current floor with `last_nonce`, awaiting the
+   * opponent's answer
+   */
+  pending_door: Option<u32>;
+  /**
+   * Whether a torch protects the player's next failed attempt
+   */
+  torch_lit: boolean;
+  /**
+   * Floors the opponent has trapped for this player; bit `floor - 1` per floor
+   */
+  trapped_floors: u32;
+}
+
+export interface Lobby {
+  config: DungeonConfig;
+  /**
+   * Ledger the lobby was created in
+   */
+  created_ledger: u32;
+  p1: PlayerState;
+  p2: PlayerState;
   player1: string;
-  player1_guess: Option<u32>;
-  player1_points: i128;
-  player2: string;
-  player2_guess: Option<u32>;
-  player2_points: i128;
+  player2: Option<string>;
+  /**
+   * Points each player locks in the hub session
+   */
+  stake: i128;
+  status: Status;
   winner: Option<string>;
-  winning_number: Option<u32>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyGuessed"},
-  4: {message:"BothPlayersNotGuessed"},
-  5: {message:"GameAlreadyEnded"}
+/**
+ * Entry in the open lobby index, enough to filter lobbies without loading them
+ */
+export interface OpenLobby {
+  created_ledger: u32;
+  depth: u32;
+  lobby_id: u32;
+  player1: string;
+  stake: i128;
+}
+
+/**
+ * A door attempt awaiting the layout owner's answer
+ */
+export interface DoorAttempt {
+  door: u32;
+  floor: u32;
+  nonce: u32;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Public view of a player's progress: no commit, nonce or inventory
+ */
+export interface PlayerProgress {
+  /**
+   * Gate floors cleared; bit `floor - 1` is set per floor
+   */
+  cleared_gates: u32;
+  committed: boolean;
+  floor: u32;
+}
+
+/**
+ * Public view of a lobby, safe to serve to spectators
+ */
+export interface LobbyProgress {
+  depth: u32;
+  gate_floors: Array<u32>;
+  p1: PlayerProgress;
+  p2: PlayerProgress;
+  player1: string;
+  player2: Option<string>;
+  stake: i128;
+  status: Status;
+  winner: Option<string>;
+}
+
+/**
+ * Groth16 verifying key over BLS12-381. `ic` holds one point per public
+ * input plus the constant term.
+ */
+export interface VerifyingKey {
+  alpha: Buffer;
+  beta: Buffer;
+  delta: Buffer;
+  gamma: Buffer;
+  ic: Array<Buffer>;
+}
+
+/**
+ * Groth16 proof of whether the attempted door is the correct one for the
+ * layout owner's committed layout
+ */
+export interface Proof {
+  a: Buffer;
+  b: Buffer;
+  c: Buffer;
+}
+
+export type DataKey = {tag: "Lobby", values: readonly [u32]} | {tag: "Admin", values: void} | {tag: "Hub", values: void} | {tag: "VerifyingKey", values: void} | {tag: "LobbyNonce", values: void} | {tag: "OpenLobbies", values: void};
 
 export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
-   * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * Construct and simulate a create_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  create_lobby: ({player1, points, config}: {player1: string, points: i128, config: DungeonConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
-   * 
-   * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * Construct and simulate a join_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  join_lobby: ({lobby_id, player2, points}: {lobby_id: u32, player2: string, points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
-   * 
-   * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * Construct and simulate a set_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_commit: ({lobby_id, player, commit}: {lobby_id: u32, player: string, commit: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a attempt_door transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Attempt `door` on the player's current floor of the opponent's layout.
+   * The attempt spends the nonce and waits for the opponent's `answer_door`;
+   * the player cannot attempt again until it is answered.
+   */
+  attempt_door: ({lobby_id, player, floor, attempt_nonce, door}: {lobby_id: u32, player: string, floor: u32, attempt_nonce: u32, door: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a answer_door transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Answer the opponent's pending attempt on the caller's layout with a
+   * Groth16 proof over (lobby_id, floor, nonce, door, opened, commit), where
+   * `commit` is the caller's. The proof must verify; `opened` says whether
+   * the door matches the layout. A verified wrong door applies the
+   * dungeon's penalties to the opponent.
+   */
+  answer_door: ({lobby_id, player, opened, proof}: {lobby_id: u32, player: string, opened: boolean, proof: Proof}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a use_item transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Spend an item from the player's inventory:
+   * - `Key` opens the door on the player's current floor without a proof;
+   * it counts as an attempt, so it waits out a cooldown, cannot be used
+   * while an attempt is pending and can be trapped
+   * - `Torch` makes the player's next verified wrong door penalty-free
+   * - `Trap` is set on the opponent's next floor and catches their first
+   * clear there (one trap per floor)
+   */
+  use_item: ({lobby_id, player, item}: {lobby_id: u32, player: string, item: Item}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a Waiting lobby. The creator may cancel while nobody has joined,
+   * or at any time once the lobby has expired unstarted. No hub session has
+   * started yet, so there is nothing to settle.
+   */
+  cancel_lobby: ({lobby_id}: {lobby_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a settle_abandoned transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle an Active lobby once nobody has moved for `ABANDON_LEDGERS`. The
+   * player the game is waiting on loses: the one owing an answer, or at a
+   * gate the one who has not cleared it. If both could move, the less
+   * recently active player loses, and if both last acted in the same ledger
+   * the session ends in a draw. Anyone may call.
+   */
+  settle_abandoned: ({lobby_id}: {lobby_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede an Active lobby. The opponent wins through the hub's `end_game`.
+   */
+  resign: ({lobby_id, player}: {lobby_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a abort transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel an Active lobby by agreement (status `Aborted`). Both players
+   * must sign; the hub session is cancelled with `cancel_game`, refunding
+   * both stakes.
+   */
+  abort: ({lobby_id}: {lobby_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_progress transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Public progress of a lobby: floors, gates, status and winner. Commits,
+   * nonces and inventories are left out; see `get_player_state`.
+   */
+  get_progress: ({lobby_id}: {lobby_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<LobbyProgress>>>
+
+  /**
+   * Construct and simulate a get_player_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * A player's full state in a lobby, including their commit and inventory.
+   * Only served to that player.
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_player_state: ({lobby_id, player}: {lobby_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PlayerState>>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game information.
-   * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Game` - The game state (includes winning number after game ends)
+   * Construct and simulate a list_open_lobbies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Joinable lobbies in creation order. `min_stake` and
+   * `creator` filter the index; `start` and `limit` page through the
+   * matches, at most `MAX_LOBBY_PAGE` at a time.
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+  list_open_lobbies: ({min_stake, creator, start, limit}: {min_stake: Option<i128>, creator: Option<string>, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<OpenLobby>>>
+
+  /**
+   * Construct and simulate a get_pending_attempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The opponent's attempt waiting for `player` to answer it on their layout
+   */
+  get_pending_attempt: ({lobby_id, player}: {lobby_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<DoorAttempt>>>>
+
+  /**
+   * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The lobby's dungeon configuration
+   */
+  get_config: ({lobby_id}: {lobby_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<DungeonConfig>>>
 
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current admin address
-   * 
-   * # Returns
-   * * `Address` - The admin address
    */
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
    * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
-   * 
-   * # Arguments
-   * * `new_admin` - The new admin address
    */
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Make a guess for the current game.
-   * Players can guess a number between 1 and 10.
-   * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player making the guess
-   * * `guess` - The guessed number (1-10)
-   */
-  make_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
-
-  /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
-   * 
-   * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
-   */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
-
-  /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have made their guesses.
-   * This generates the winning number, determines the winner, and ends the session.
-   * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
-   */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_verifying_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_verifying_key: (options?: MethodOptions) => Promise<AssembledTransaction<Option<VerifyingKey>>>
+
+  /**
+   * Construct and simulate a set_verifying_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_verifying_key: ({vk}: {vk: VerifyingKey}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({new_hash}: {new_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -181,31 +423,69 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAIAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAOd2lubmluZ19udW1iZXIAAAAAA+gAAAAE",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAF",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAJ1HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB3aW5uaW5nIG51bWJlciBhZnRlciBnYW1lIGVuZHMpAAAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
-        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAOJNYWtlIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGJldHdlZW4gMSBhbmQgMTAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcwoqIGBndWVzc2AgLSBUaGUgZ3Vlc3NlZCBudW1iZXIgKDEtMTApAAAAAAAKbWFrZV9ndWVzcwAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAATtSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIG1hZGUgdGhlaXIgZ3Vlc3Nlcy4KVGhpcyBnZW5lcmF0ZXMgdGhlIHdpbm5pbmcgbnVtYmVyLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==" ]),
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGgAAAAAAAAANTG9iYnlOb3RGb3VuZAAAAAAAAAEAAAAAAAAADUxvYmJ5SW5hY3RpdmUAAAAAAAACAAAAAAAAAA1BbHJlYWR5Sm9pbmVkAAAAAAAAAwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAQAAAAAAAAACU5vdFBsYXllcgAAAAAAAAUAAAAAAAAACldyb25nRmxvb3IAAAAAAAYAAAAAAAAACEJhZE5vbmNlAAAABwAAAAAAAAAKTm90V2FpdGluZwAAAAAACAAAAAAAAAAMR2FtZUZpbmlzaGVkAAAACQAAAAAAAAANTWlzc2luZ0NvbW1pdAAAAAAAAAoAAAAAAAAAElZlcmlmeWluZ0tleU5vdFNldAAAAAAACwAAAAAAAAATSW52YWxpZFZlcmlmeWluZ0tleQAAAAAMAAAAAAAAAA1JbnZhbGlkQ29tbWl0AAAAAAAADQAAAAAAAAALTG9iYnlFeGlzdHMAAAAADgAAAAAAAAALSW52YWxpZERvb3IAAAAADwAAAAAAAAAMTG9iYnlFeHBpcmVkAAAAEAAAAAAAAAAKTm90RXhwaXJlZAAAAAAAEQAAAAAAAAAMSW52YWxpZFN0YWtlAAAAEgAAAAAAAAANU3Rha2VNaXNtYXRjaAAAAAAAABMAAAAAAAAADUludmFsaWRDb25maWcAAAAAAAAUAAAAAAAAAAxJbnZhbGlkUHJvb2YAAAAVAAAAAAAAAAtDb29saW5nRG93bgAAAAAWAAAAAAAAAAZOb0l0ZW0AAAAAABcAAAAAAAAADkludmFsaWRJdGVtVXNlAAAAAAAYAAAAAAAAAA5BdHRlbXB0UGVuZGluZwAAAAAAGQAAAAAAAAAQTm9QZW5kaW5nQXR0ZW1wdAAAABo=",
+        "AAAAAgAAAAAAAAAAAAAABlN0YXR1cwAAAAAABgAAAAAAAAAAAAAAB1dhaXRpbmcAAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAhGaW5pc2hlZAAAAAAAAAAmV2FpdGluZyBsb2JieSB3aXRoZHJhd24gYnkgaXRzIGNyZWF0b3IAAAAAAAlDYW5jZWxsZWQAAAAAAAAAAAAAMkFjdGl2ZSBsb2JieSBzZXR0bGVkIGFmdGVyIGEgcGxheWVyIHN0b3BwZWQgYWN0aW5nAAAAAAAJQWJhbmRvbmVkAAAAAAAAAAAAADdBY3RpdmUgbG9iYnkgY2FuY2VsbGVkIGJ5IGJvdGggcGxheWVycywgc3Rha2VzIHJlZnVuZGVkAAAAAAdBYm9ydGVkAA==",
+        "AAAAAgAAABpJdGVtcyBmb3VuZCBpbiB0aGUgZHVuZ2VvbgAAAAAAAAAAAARJdGVtAAAAAwAAAAAAAAA8T3BlbnMgdGhlIGRvb3Igb24gdGhlIHBsYXllcidzIGN1cnJlbnQgZmxvb3Igd2l0aG91dCBhIHByb29mAAAAA0tleQAAAAAAAAAAdUxpZ2h0cyB0aGUgZmxvb3I6IHRoZSBwbGF5ZXIncyBuZXh0IHZlcmlmaWVkIHdyb25nIGRvb3IgY2FycmllcyBubwpwZW5hbHR5LiBJdCByZXZlYWxzIG5vdGhpbmcgZWxzZSBhYm91dCB0aGUgbGF5b3V0LgAAAAAAAAVUb3JjaAAAAAAAAAAAAABEU2V0IG9uIHRoZSBvcHBvbmVudCdzIG5leHQgZmxvb3IsIHdoZXJlIGl0IGNhdGNoZXMgdGhlaXIgZmlyc3QgY2xlYXIAAAAEVHJhcA==",
+        "AAAAAQAAAFZBbiBpdGVtIGx5aW5nIG9uIGEgZmxvb3I7IGVhY2ggcGxheWVyIHBpY2tzIGl0IHVwIHRoZSBmaXJzdCB0aW1lIHRoZXkKY2xlYXIgdGhhdCBmbG9vcgAAAAAAAAAAAAlJdGVtR3JhbnQAAAAAAAACAAAAAAAAAAVmbG9vcgAAAAAAAAQAAAAAAAAABGl0ZW0AAAfQAAAABEl0ZW0=",
+        "AAAAAQAAAAAAAAAAAAAACUludmVudG9yeQAAAAAAAAMAAAAAAAAABGtleXMAAAAEAAAAAAAAAAd0b3JjaGVzAAAAAAQAAAAAAAAABXRyYXBzAAAAAAAABA==",
+        "AAAAAQAAACZEdW5nZW9uIHNoYXBlLCBmaXhlZCBhdCBsb2JieSBjcmVhdGlvbgAAAAAAAAAAAA1EdW5nZW9uQ29uZmlnAAAAAAAABgAAAEJMZWRnZXJzIGEgcGxheWVyIG11c3Qgd2FpdCBhZnRlciBhIGZhaWxlZCBhdHRlbXB0OyAwID0gbm8gY29vbGRvd24AAAAAABBjb29sZG93bl9sZWRnZXJzAAAABAAAADtOdW1iZXIgb2YgZmxvb3JzLCAxLi49TUFYX0RFUFRIOyBjbGVhcmluZyB0aGUgbGFzdCBvbmUgd2lucwAAAAAFZGVwdGgAAAAAAAAEAAAAo0Nvb3BlcmF0aXZlIGdhdGUgZmxvb3JzIGluIGFzY2VuZGluZyBvcmRlcjogYm90aCBwbGF5ZXJzIG11c3QgY2xlYXIgYQpnYXRlIGZsb29yIGJlZm9yZSBlaXRoZXIgbW92ZXMgcGFzdCBpdC4gVGhlIGxhc3QgZmxvb3IgZGVjaWRlcyB0aGUgcmFjZQphbmQgY2Fubm90IGJlIGEgZ2F0ZS4AAAAAC2dhdGVfZmxvb3JzAAAAA+oAAAAEAAAAKUl0ZW1zIHBsYWNlZCBvbiBmbG9vcnMgYmVsb3cgdGhlIGxhc3Qgb25lAAAAAAAAC2l0ZW1fZ3JhbnRzAAAAA+oAAAfQAAAACUl0ZW1HcmFudAAAAAAAAH1XaGV0aGVyIGEgZmFpbGVkIGF0dGVtcHQga25vY2tzIHRoZSBwbGF5ZXIgYmFjayBvbmUgZmxvb3IuIEdhdGVzIGFyZQpjaGVja3BvaW50czogbm9ib2R5IGlzIGtub2NrZWQgb2ZmIG9yIG9udG8gYSBnYXRlIGZsb29yLgAAAAAAAAlrbm9ja2JhY2sAAAAAAAABAAAATUZhaWxlZCBhdHRlbXB0cyBhbGxvd2VkIG9uIG9uZSBmbG9vciBiZWZvcmUgdGhlIHBsYXllciBmb3JmZWl0czsgMCA9IG5vIGxpbWl0AAAAAAAADG1heF9mYWlsdXJlcwAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAAC1BsYXllclN0YXRlAAAAAA0AAABFR2F0ZSBmbG9vcnMgdGhpcyBwbGF5ZXIgaGFzIGNsZWFyZWQ7IGJpdCBgZmxvb3IgLSAxYCBpcyBzZXQgcGVyIGZsb29yAAAAAAAADWNsZWFyZWRfZ2F0ZXMAAAAAAAAEAAAAAAAAAAZjb21taXQAAAAAA+gAAAPuAAAAIAAAAEBGaXJzdCBsZWRnZXIgdGhlIHBsYXllciBtYXkgYXR0ZW1wdCBhIGRvb3IgYWdhaW4gYWZ0ZXIgYSBmYWlsdXJlAAAADmNvb2xkb3duX3VudGlsAAAAAAAEAAAALFZlcmlmaWVkIGZhaWxlZCBhdHRlbXB0cyBvdmVyIHRoZSB3aG9sZSBnYW1lAAAAD2ZhaWxlZF9hdHRlbXB0cwAAAAAEAAAAAAAAAAVmbG9vcgAAAAAAAAQAAAAtVmVyaWZpZWQgZmFpbGVkIGF0dGVtcHRzIG9uIHRoZSBjdXJyZW50IGZsb29yAAAAAAAADmZsb29yX2ZhaWx1cmVzAAAAAAAEAAAAAAAAAAlpbnZlbnRvcnkAAAAAAAfQAAAACUludmVudG9yeQAAAAAAADJMZWRnZXIgb2YgdGhlIHBsYXllcidzIGxhc3QgY29tbWl0IG9yIGRvb3IgYXR0ZW1wdAAAAAAAC2xhc3RfYWN0aXZlAAAAAAQAAAAAAAAACmxhc3Rfbm9uY2UAAAAAAAQAAABHRmxvb3JzIHdob3NlIGl0ZW1zIHRoaXMgcGxheWVyIGhhcyBwaWNrZWQgdXA7IGJpdCBgZmxvb3IgLSAxYCBwZXIgZmxvb3IAAAAADWxvb3RlZF9mbG9vcnMAAAAAAAAEAAAAUURvb3IgdHJpZWQgb24gdGhlIGN1cnJlbnQgZmxvb3Igd2l0aCBgbGFzdF9ub25jZWAsIGF3YWl0aW5nIHRoZQpvcHBvbmVudCdzIGFuc3dlcgAAAAAAAAxwZW5kaW5nX2Rvb3IAAAPoAAAABAAAADlXaGV0aGVyIGEgdG9yY2ggcHJvdGVjdHMgdGhlIHBsYXllcidzIG5leHQgZmFpbGVkIGF0dGVtcHQAAAAAAAAJdG9yY2hfbGl0AAAAAAAAAQAAAEpGbG9vcnMgdGhlIG9wcG9uZW50IGhhcyB0cmFwcGVkIGZvciB0aGlzIHBsYXllcjsgYml0IGBmbG9vciAtIDFgIHBlciBmbG9vcgAAAAAADnRyYXBwZWRfZmxvb3JzAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAABUxvYmJ5AAAAAAAACQAAAAAAAAAGY29uZmlnAAAAAAfQAAAADUR1bmdlb25Db25maWcAAAAAAAAfTGVkZ2VyIHRoZSBsb2JieSB3YXMgY3JlYXRlZCBpbgAAAAAOY3JlYXRlZF9sZWRnZXIAAAAAAAQAAAAAAAAAAnAxAAAAAAfQAAAAC1BsYXllclN0YXRlAAAAAAAAAAACcDIAAAAAB9AAAAALUGxheWVyU3RhdGUAAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAD6AAAABMAAAArUG9pbnRzIGVhY2ggcGxheWVyIGxvY2tzIGluIHRoZSBodWIgc2Vzc2lvbgAAAAAFc3Rha2UAAAAAAAALAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAGU3RhdHVzAAAAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAAExFbnRyeSBpbiB0aGUgb3BlbiBsb2JieSBpbmRleCwgZW5vdWdoIHRvIGZpbHRlciBsb2JiaWVzIHdpdGhvdXQgbG9hZGluZyB0aGVtAAAAAAAAAAlPcGVuTG9iYnkAAAAAAAAFAAAAAAAAAA5jcmVhdGVkX2xlZGdlcgAAAAAABAAAAAAAAAAFZGVwdGgAAAAAAAAEAAAAAAAAAAhsb2JieV9pZAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAFc3Rha2UAAAAAAAAL",
+        "AAAAAQAAADFBIGRvb3IgYXR0ZW1wdCBhd2FpdGluZyB0aGUgbGF5b3V0IG93bmVyJ3MgYW5zd2VyAAAAAAAAAAAAAAtEb29yQXR0ZW1wdAAAAAADAAAAAAAAAARkb29yAAAABAAAAAAAAAAFZmxvb3IAAAAAAAAEAAAAAAAAAAVub25jZQAAAAAAAAQ=",
+        "AAAAAQAAAEFQdWJsaWMgdmlldyBvZiBhIHBsYXllcidzIHByb2dyZXNzOiBubyBjb21taXQsIG5vbmNlIG9yIGludmVudG9yeQAAAAAAAAAAAAAOUGxheWVyUHJvZ3Jlc3MAAAAAAAMAAAA1R2F0ZSBmbG9vcnMgY2xlYXJlZDsgYml0IGBmbG9vciAtIDFgIGlzIHNldCBwZXIgZmxvb3IAAAAAAAANY2xlYXJlZF9nYXRlcwAAAAAAAAQAAAAAAAAACWNvbW1pdHRlZAAAAAAAAAEAAAAAAAAABWZsb29yAAAAAAAABA==",
+        "AAAAAQAAADNQdWJsaWMgdmlldyBvZiBhIGxvYmJ5LCBzYWZlIHRvIHNlcnZlIHRvIHNwZWN0YXRvcnMAAAAAAAAAAA1Mb2JieVByb2dyZXNzAAAAAAAACQAAAAAAAAAFZGVwdGgAAAAAAAAEAAAAAAAAAAtnYXRlX2Zsb29ycwAAAAPqAAAABAAAAAAAAAACcDEAAAAAB9AAAAAOUGxheWVyUHJvZ3Jlc3MAAAAAAAAAAAACcDIAAAAAB9AAAAAOUGxheWVyUHJvZ3Jlc3MAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAA+gAAAATAAAAAAAAAAVzdGFrZQAAAAAAAAsAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAZTdGF0dXMAAAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAQAAAGNHcm90aDE2IHZlcmlmeWluZyBrZXkgb3ZlciBCTFMxMi0zODEuIGBpY2AgaG9sZHMgb25lIHBvaW50IHBlciBwdWJsaWMKaW5wdXQgcGx1cyB0aGUgY29uc3RhbnQgdGVybS4AAAAAAAAAAAxWZXJpZnlpbmdLZXkAAAAFAAAAAAAAAAVhbHBoYQAAAAAAA+4AAABgAAAAAAAAAARiZXRhAAAD7gAAAMAAAAAAAAAABWRlbHRhAAAAAAAD7gAAAMAAAAAAAAAABWdhbW1hAAAAAAAD7gAAAMAAAAAAAAAAAmljAAAAAAPqAAAD7gAAAGA=",
+        "AAAAAQAAAGZHcm90aDE2IHByb29mIG9mIHdoZXRoZXIgdGhlIGF0dGVtcHRlZCBkb29yIGlzIHRoZSBjb3JyZWN0IG9uZSBmb3IgdGhlCmxheW91dCBvd25lcidzIGNvbW1pdHRlZCBsYXlvdXQAAAAAAAAAAAAFUHJvb2YAAAAAAAADAAAAAAAAAAFhAAAAAAAD7gAAAGAAAAAAAAAAAWIAAAAAAAPuAAAAwAAAAAAAAAABYwAAAAAAA+4AAABg",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABgAAAAEAAAAAAAAABUxvYmJ5AAAAAAAAAQAAAAQAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAAA0h1YgAAAAAAAAAAAAAAAAxWZXJpZnlpbmdLZXkAAAAAAAAAAAAAAApMb2JieU5vbmNlAAAAAAAAAAAAAAAAAAtPcGVuTG9iYmllcwA=",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAMY3JlYXRlX2xvYmJ5AAAAAwAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAABmNvbmZpZwAAAAAH0AAAAA1EdW5nZW9uQ29uZmlnAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAAAAAAAKam9pbl9sb2JieQAAAAAAAwAAAAAAAAAIbG9iYnlfaWQAAAAEAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAABnBvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAKc2V0X2NvbW1pdAAAAAAAAwAAAAAAAAAIbG9iYnlfaWQAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmNvbW1pdAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAMVBdHRlbXB0IGBkb29yYCBvbiB0aGUgcGxheWVyJ3MgY3VycmVudCBmbG9vciBvZiB0aGUgb3Bwb25lbnQncyBsYXlvdXQuClRoZSBhdHRlbXB0IHNwZW5kcyB0aGUgbm9uY2UgYW5kIHdhaXRzIGZvciB0aGUgb3Bwb25lbnQncyBgYW5zd2VyX2Rvb3JgOwp0aGUgcGxheWVyIGNhbm5vdCBhdHRlbXB0IGFnYWluIHVudGlsIGl0IGlzIGFuc3dlcmVkLgAAAAAAAAxhdHRlbXB0X2Rvb3IAAAAFAAAAAAAAAAhsb2JieV9pZAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFZmxvb3IAAAAAAAAEAAAAAAAAAA1hdHRlbXB0X25vbmNlAAAAAAAABAAAAAAAAAAEZG9vcgAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAATdBbnN3ZXIgdGhlIG9wcG9uZW50J3MgcGVuZGluZyBhdHRlbXB0IG9uIHRoZSBjYWxsZXIncyBsYXlvdXQgd2l0aCBhCkdyb3RoMTYgcHJvb2Ygb3ZlciAobG9iYnlfaWQsIGZsb29yLCBub25jZSwgZG9vciwgb3BlbmVkLCBjb21taXQpLCB3aGVyZQpgY29tbWl0YCBpcyB0aGUgY2FsbGVyJ3MuIFRoZSBwcm9vZiBtdXN0IHZlcmlmeTsgYG9wZW5lZGAgc2F5cyB3aGV0aGVyCnRoZSBkb29yIG1hdGNoZXMgdGhlIGxheW91dC4gQSB2ZXJpZmllZCB3cm9uZyBkb29yIGFwcGxpZXMgdGhlCmR1bmdlb24ncyBwZW5hbHRpZXMgdG8gdGhlIG9wcG9uZW50LgAAAAALYW5zd2VyX2Rvb3IAAAAABAAAAAAAAAAIbG9iYnlfaWQAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABm9wZW5lZAAAAAAAAQAAAAAAAAAFcHJvb2YAAAAAAAfQAAAABVByb29mAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAYxTcGVuZCBhbiBpdGVtIGZyb20gdGhlIHBsYXllcidzIGludmVudG9yeToKLSBgS2V5YCBvcGVucyB0aGUgZG9vciBvbiB0aGUgcGxheWVyJ3MgY3VycmVudCBmbG9vciB3aXRob3V0IGEgcHJvb2Y7Cml0IGNvdW50cyBhcyBhbiBhdHRlbXB0LCBzbyBpdCB3YWl0cyBvdXQgYSBjb29sZG93biwgY2Fubm90IGJlIHVzZWQKd2hpbGUgYW4gYXR0ZW1wdCBpcyBwZW5kaW5nIGFuZCBjYW4gYmUgdHJhcHBlZAotIGBUb3JjaGAgbWFrZXMgdGhlIHBsYXllcidzIG5leHQgdmVyaWZpZWQgd3JvbmcgZG9vciBwZW5hbHR5LWZyZWUKLSBgVHJhcGAgaXMgc2V0IG9uIHRoZSBvcHBvbmVudCdzIG5leHQgZmxvb3IgYW5kIGNhdGNoZXMgdGhlaXIgZmlyc3QKY2xlYXIgdGhlcmUgKG9uZSB0cmFwIHBlciBmbG9vcikAAAAIdXNlX2l0ZW0AAAADAAAAAAAAAAhsb2JieV9pZAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEaXRlbQAAB9AAAAAESXRlbQAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAL1XaXRoZHJhdyBhIFdhaXRpbmcgbG9iYnkuIFRoZSBjcmVhdG9yIG1heSBjYW5jZWwgd2hpbGUgbm9ib2R5IGhhcyBqb2luZWQsCm9yIGF0IGFueSB0aW1lIG9uY2UgdGhlIGxvYmJ5IGhhcyBleHBpcmVkIHVuc3RhcnRlZC4gTm8gaHViIHNlc3Npb24gaGFzCnN0YXJ0ZWQgeWV0LCBzbyB0aGVyZSBpcyBub3RoaW5nIHRvIHNldHRsZS4AAAAAAAAMY2FuY2VsX2xvYmJ5AAAAAQAAAAAAAAAIbG9iYnlfaWQAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAURTZXR0bGUgYW4gQWN0aXZlIGxvYmJ5IG9uY2Ugbm9ib2R5IGhhcyBtb3ZlZCBmb3IgYEFCQU5ET05fTEVER0VSU2AuIFRoZQpwbGF5ZXIgdGhlIGdhbWUgaXMgd2FpdGluZyBvbiBsb3NlczogdGhlIG9uZSBvd2luZyBhbiBhbnN3ZXIsIG9yIGF0IGEKZ2F0ZSB0aGUgb25lIHdobyBoYXMgbm90IGNsZWFyZWQgaXQuIElmIGJvdGggY291bGQgbW92ZSwgdGhlIGxlc3MKcmVjZW50bHkgYWN0aXZlIHBsYXllciBsb3NlcywgYW5kIGlmIGJvdGggbGFzdCBhY3RlZCBpbiB0aGUgc2FtZSBsZWRnZXIKdGhlIHNlc3Npb24gZW5kcyBpbiBhIGRyYXcuIEFueW9uZSBtYXkgY2FsbC4AAAAQc2V0dGxlX2FiYW5kb25lZAAAAAEAAAAAAAAACGxvYmJ5X2lkAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAEhDb25jZWRlIGFuIEFjdGl2ZSBsb2JieS4gVGhlIG9wcG9uZW50IHdpbnMgdGhyb3VnaCB0aGUgaHViJ3MgYGVuZF9nYW1lYC4AAAAGcmVzaWduAAAAAAACAAAAAAAAAAhsb2JieV9pZAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJdDYW5jZWwgYW4gQWN0aXZlIGxvYmJ5IGJ5IGFncmVlbWVudCAoc3RhdHVzIGBBYm9ydGVkYCkuIEJvdGggcGxheWVycwptdXN0IHNpZ247IHRoZSBodWIgc2Vzc2lvbiBpcyBjYW5jZWxsZWQgd2l0aCBgY2FuY2VsX2dhbWVgLCByZWZ1bmRpbmcKYm90aCBzdGFrZXMuAAAAAAVhYm9ydAAAAAAAAAEAAAAAAAAACGxvYmJ5X2lkAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAINQdWJsaWMgcHJvZ3Jlc3Mgb2YgYSBsb2JieTogZmxvb3JzLCBnYXRlcywgc3RhdHVzIGFuZCB3aW5uZXIuIENvbW1pdHMsCm5vbmNlcyBhbmQgaW52ZW50b3JpZXMgYXJlIGxlZnQgb3V0OyBzZWUgYGdldF9wbGF5ZXJfc3RhdGVgLgAAAAAMZ2V0X3Byb2dyZXNzAAAAAQAAAAAAAAAIbG9iYnlfaWQAAAAEAAAAAQAAA+gAAAfQAAAADUxvYmJ5UHJvZ3Jlc3MAAAA=",
+        "AAAAAAAAAGNBIHBsYXllcidzIGZ1bGwgc3RhdGUgaW4gYSBsb2JieSwgaW5jbHVkaW5nIHRoZWlyIGNvbW1pdCBhbmQgaW52ZW50b3J5LgpPbmx5IHNlcnZlZCB0byB0aGF0IHBsYXllci4AAAAAEGdldF9wbGF5ZXJfc3RhdGUAAAACAAAAAAAAAAhsb2JieV9pZAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAH0AAAAAtQbGF5ZXJTdGF0ZQAAAAAD",
+        "AAAAAAAAAKFKb2luYWJsZSBsb2JiaWVzIGluIGNyZWF0aW9uIG9yZGVyLiBgbWluX3N0YWtlYCBhbmQKYGNyZWF0b3JgIGZpbHRlciB0aGUgaW5kZXg7IGBzdGFydGAgYW5kIGBsaW1pdGAgcGFnZSB0aHJvdWdoIHRoZQptYXRjaGVzLCBhdCBtb3N0IGBNQVhfTE9CQllfUEFHRWAgYXQgYSB0aW1lLgAAAAAAABFsaXN0X29wZW5fbG9iYmllcwAAAAAAAAQAAAAAAAAACW1pbl9zdGFrZQAAAAAAA+gAAAALAAAAAAAAAAdjcmVhdG9yAAAAA+gAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAAlPcGVuTG9iYnkAAAA=",
+        "AAAAAAAAAEhUaGUgb3Bwb25lbnQncyBhdHRlbXB0IHdhaXRpbmcgZm9yIGBwbGF5ZXJgIHRvIGFuc3dlciBpdCBvbiB0aGVpciBsYXlvdXQAAAATZ2V0X3BlbmRpbmdfYXR0ZW1wdAAAAAACAAAAAAAAAAhsb2JieV9pZAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD6AAAB9AAAAALRG9vckF0dGVtcHQAAAAAAw==",
+        "AAAAAAAAACFUaGUgbG9iYnkncyBkdW5nZW9uIGNvbmZpZ3VyYXRpb24AAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAQAAAAAAAAAIbG9iYnlfaWQAAAAEAAAAAQAAA+kAAAfQAAAADUR1bmdlb25Db25maWcAAAAAAAAD",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAARZ2V0X3ZlcmlmeWluZ19rZXkAAAAAAAAAAAAAAQAAA+gAAAfQAAAADFZlcmlmeWluZ0tleQ==",
+        "AAAAAAAAAAAAAAARc2V0X3ZlcmlmeWluZ19rZXkAAAAAAAABAAAAAAAAAAJ2awAAAAAH0AAAAAxWZXJpZnlpbmdLZXkAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAAhuZXdfaGFzaAAAA+4AAAAgAAAAAA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
-        get_game: this.txFromJSON<Result<Game>>,
+    create_lobby: this.txFromJSON<Result<u32>>,
+        join_lobby: this.txFromJSON<Result<void>>,
+        set_commit: this.txFromJSON<Result<void>>,
+        attempt_door: this.txFromJSON<Result<void>>,
+        answer_door: this.txFromJSON<Result<void>>,
+        use_item: this.txFromJSON<Result<void>>,
+        cancel_lobby: this.txFromJSON<Result<void>>,
+        settle_abandoned: this.txFromJSON<Result<void>>,
+        resign: this.txFromJSON<Result<void>>,
+        abort: this.txFromJSON<Result<void>>,
+        get_progress: this.txFromJSON<Option<LobbyProgress>>,
+        get_player_state: this.txFromJSON<Result<PlayerState>>,
+        list_open_lobbies: this.txFromJSON<Array<OpenLobby>>,
+        get_pending_attempt: this.txFromJSON<Result<Option<DoorAttempt>>>,
+        get_config: this.txFromJSON<Result<DungeonConfig>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        make_guess: this.txFromJSON<Result<void>>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        get_verifying_key: this.txFromJSON<Option<VerifyingKey>>,
+        set_verifying_key: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
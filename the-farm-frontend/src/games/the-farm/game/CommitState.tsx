@@ -26,7 +26,8 @@ export function CommitState() {
     try {
       const res = await setCommit(
         Number(lobbyId.replace("L", "")),
-        Buffer.from(commitInput),
+        // 32-byte big-endian field element, left-padded
+        Buffer.from(commitInput.replace(/^0x/, "").padStart(64, "0"), "hex"),
         publicKey,
         signer
       );
@@ -46,7 +47,7 @@ export function CommitState() {
           className="tf-input tf-input--compact"
           value={commitInput}
           onChange={(e) => setCommitInput(e.target.value)}
-          placeholder="commit bytes"
+          placeholder="commit (hex)"
         />
         <button className="tf-button tf-button--line" onClick={handleCommit}>
           Send commit
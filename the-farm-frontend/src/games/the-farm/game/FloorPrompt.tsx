@@ -3,7 +3,7 @@ import { FLOOR_DESIGNS } from "../lore/floors";
 
 type FloorPromptProps = {
     floor: number;
-    onAttempt: (isCorrect: boolean) => void;
+    onAttempt: (door: number) => void;
     busy: boolean;
 };
 
@@ -32,7 +32,7 @@ export function FloorPrompt({ floor, onAttempt, busy }: FloorPromptProps) {
                             key={idx}
                             className="tf-option-btn"
                             disabled={busy}
-                            onClick={() => onAttempt(idx === design.correctOptionIndex)}
+                            onClick={() => onAttempt(idx)}
                         >
                             <div className="tf-option-label">{opt.label}</div>
                             <div className="tf-option-text">{opt.text}</div>
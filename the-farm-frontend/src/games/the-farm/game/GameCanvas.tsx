@@ -2,7 +2,7 @@ import { useEffect, useRef, useState } from "react";
 import { FloorPrompt } from "./FloorPrompt";
 import { useLobbyContext } from "./LobbyContext";
 import { useWallet } from "@/hooks/useWallet";
-import { attemptDoor as apiAttemptDoor } from "../theFarmApi";
+import { attemptDoor as apiAttemptDoor, fetchLobby } from "../theFarmApi";
 import { initScene, disposeScene } from "./threeScene";
 import { floorProofPlan } from "../proofPlan";
 // @ts-ignore
@@ -36,8 +36,9 @@ export function GameCanvas() {
   const setFloors = (p1: number, p2: number, p1s: any, p2s: any) => {
     setP1Floor(p1);
     setP2Floor(p2);
-    setP1Gates({ g1: p1s.cleared_gate1, g5: p1s.cleared_gate5 });
-    setP2Gates({ g1: p2s.cleared_gate1, g5: p2s.cleared_gate5 });
+    // `cleared_gates` sets bit `floor - 1` for every gate floor cleared
+    setP1Gates({ g1: (p1s.cleared_gates & 1) !== 0, g5: (p1s.cleared_gates & (1 << 4)) !== 0 });
+    setP2Gates({ g1: (p2s.cleared_gates & 1) !== 0, g5: (p2s.cleared_gates & (1 << 4)) !== 0 });
   };
 
   const bumpNonce = () => setAttemptNonce((n) => n + 1);
@@ -101,7 +102,7 @@ export function GameCanvas() {
     }
   };
 
-  const attemptDoor = async (isCorrectOption: boolean) => {
+  const attemptDoor = async (door: number) => {
     setAttempts((a) => a + 1);
     const signer = safeSigner();
     if (!signer || !publicKey || !lobbyId) {
@@ -115,13 +116,13 @@ export function GameCanvas() {
         Number(lobbyId.replace("L", "")),
         role === "player1" ? p1Floor : p2Floor,
         attemptNonce + 1,
+        door,
         publicKey,
-        isCorrectOption,
         signer
       );
       setTxHash(res.hash);
       bumpNonce();
-      setResult("Attempt submitted. Waiting for ledger...");
+      setResult("Attempt submitted. Waiting for the opponent's proof...");
     } catch (e: any) {
       setAttemptError(e?.message || "Attempt failed");
       setResult("Attempt failed");
@@ -6,7 +6,12 @@ import { useLobbyContext } from "../game/LobbyContext";
 import type { LobbyRole } from "../game/LobbyContext";
 import { useWallet } from "@/hooks/useWallet";
 import type { ContractSigner } from "@/types/signer";
-import { fetchLobby, createLobby as apiCreateLobby, joinLobby as apiJoinLobby } from "../theFarmApi";
+import {
+  CLASSIC_DUNGEON,
+  fetchLobby,
+  createLobby as apiCreateLobby,
+  joinLobby as apiJoinLobby,
+} from "../theFarmApi";
 import "./theFarmShell.css";
 
 const explorer = (hash: string) => `https://stellar.expert/explorer/testnet/tx/${hash}`;
@@ -87,7 +92,11 @@ export function TheFarmLobby() {
     setLobbyState(newLobby);
     setLobbyContext(newLobby.lobbyId, "player1");
     try {
-      const res = await apiCreateLobby(publicKey, signer);
+      const res = await apiCreateLobby(publicKey, 0n, CLASSIC_DUNGEON, signer);
+      // The contract hashes the lobby id, so switch to the one it returned
+      const lobbyId = `L${res.lobbyId}`;
+      setLobbyState({ ...newLobby, lobbyId });
+      setLobbyContext(lobbyId, "player1");
       setTxHash(res.hash);
     } catch (e: any) {
       setError(e?.message || "Create lobby failed");
@@ -113,7 +122,10 @@ export function TheFarmLobby() {
     setLobbyState(joined);
     setLobbyContext(joined.lobbyId, "player2");
     try {
-      const res = await apiJoinLobby(Number(joiningCode.replace("L", "")), publicKey, signer);
+      const id = Number(joiningCode.replace("L", ""));
+      // The joiner must match the creator's stake
+      const progress = await fetchLobby(id);
+      const res = await apiJoinLobby(id, publicKey, progress?.stake ?? 0n, signer);
       setTxHash(res.hash);
       // Optimistically set active if we just joined? 
       // Actually better to wait for chain poll or success.
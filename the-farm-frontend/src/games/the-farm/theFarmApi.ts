@@ -1,10 +1,20 @@
-import { Client } from "../../bindings/the_farm/src/index";
+import { Client, type DungeonConfig, type Proof } from "./bindings";
 import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS } from "@/utils/constants";
 import type { contract } from "@stellar/stellar-sdk";
 import { Buffer } from "buffer";
 
 const contractId = import.meta.env.VITE_THE_FARM_CONTRACT_ID;
 
+// The classic dungeon: ten floors, gates on 1 and 5, no penalties or items.
+export const CLASSIC_DUNGEON: DungeonConfig = {
+  depth: 10,
+  gate_floors: [1, 5],
+  max_failures: 0,
+  knockback: false,
+  cooldown_ledgers: 0,
+  item_grants: [],
+};
+
 const readonlyClient = new Client({
   contractId,
   networkPassphrase: NETWORK_PASSPHRASE,
@@ -25,27 +35,32 @@ function signingClient(publicKey: string, signer: Signer) {
 }
 
 export async function fetchLobby(lobbyId: number) {
-  const tx = await readonlyClient.get_lobby({ lobby_id: lobbyId });
+  const tx = await readonlyClient.get_progress({ lobby_id: lobbyId });
   const sim = await tx.simulate();
-  if (sim.result.isOk()) {
-    return sim.result.unwrap();
-  }
-  return null;
+  return sim.result ?? null;
 }
 
-export async function createLobby(player: string, signer: Signer) {
+export async function createLobby(
+  player: string,
+  points: bigint,
+  config: DungeonConfig,
+  signer: Signer
+) {
   const client = signingClient(player, signer);
-  const tx = await client.create_lobby({ player1: player }, DEFAULT_METHOD_OPTIONS);
+  const tx = await client.create_lobby(
+    { player1: player, points, config },
+    DEFAULT_METHOD_OPTIONS
+  );
   const sim = await tx.simulate();
   if (!sim.result.isOk()) throw new Error("Simulation failed");
   const sent = await tx.signAndSend();
   return { lobbyId: sim.result.unwrap(), hash: sent.hash };
 }
 
-export async function joinLobby(lobbyId: number, player: string, signer: Signer) {
+export async function joinLobby(lobbyId: number, player: string, points: bigint, signer: Signer) {
   const client = signingClient(player, signer);
   const tx = await client.join_lobby(
-    { lobby_id: lobbyId, player2: player },
+    { lobby_id: lobbyId, player2: player, points },
     DEFAULT_METHOD_OPTIONS
   );
   await tx.simulate();
@@ -57,17 +72,18 @@ export async function attemptDoor(
   lobbyId: number,
   floor: number,
   nonce: number,
+  door: number,
   player: string,
-  isCorrect: boolean,
   signer: Signer
 ) {
   const client = signingClient(player, signer);
   const tx = await client.attempt_door(
     {
       lobby_id: lobbyId,
+      player,
       floor,
       attempt_nonce: nonce,
-      is_correct: isCorrect,
+      door,
     },
     DEFAULT_METHOD_OPTIONS
   );
@@ -77,10 +93,27 @@ export async function attemptDoor(
   return { hash: sent.hash };
 }
 
+export async function answerDoor(
+  lobbyId: number,
+  opened: boolean,
+  proof: Proof,
+  player: string,
+  signer: Signer
+) {
+  const client = signingClient(player, signer);
+  const tx = await client.answer_door(
+    { lobby_id: lobbyId, player, opened, proof },
+    DEFAULT_METHOD_OPTIONS
+  );
+  await tx.simulate();
+  const sent = await tx.signAndSend();
+  return { hash: sent.hash };
+}
+
 export async function setCommit(lobbyId: number, commit: Buffer, player: string, signer: Signer) {
   const client = signingClient(player, signer);
   const tx = await client.set_commit(
-    { lobby_id: lobbyId, commit },
+    { lobby_id: lobbyId, player, commit },
     DEFAULT_METHOD_OPTIONS
   );
   await tx.simulate();